@@ -1094,20 +1094,6 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
         }
     }
 
-    pub fn resolve_named_tuple_element(&self, attr: Attribute) -> Option<Type> {
-        // NamedTuples are immutable, so their attributes are always read-only
-        // NOTE(grievejia): We do not use `__getattr__` here because this lookup is expected to be inovked
-        // on NamedTuple attributes with known names.
-        match attr.inner {
-            AttributeInner::Simple(ty, Visibility::ReadOnly) => Some(ty),
-            AttributeInner::Simple(_, Visibility::ReadWrite)
-            | AttributeInner::NoAccess(_)
-            | AttributeInner::Property(..)
-            | AttributeInner::Descriptor(..)
-            | AttributeInner::GetAttr(..) => None,
-        }
-    }
-
     /// A convenience function for callers which want an error but do not need to distinguish
     /// between NotFound and Error results.
     fn get_type_or_conflated_error_msg(
@@ -1142,8 +1128,12 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
             {
                 LookupResult::found_type(Type::Literal(Lit::Str(member.as_str().into())))
             }
-            AttributeBase::EnumLiteral(_, _, raw_type)
-                if matches!(attr_name.as_str(), "value" | "_value_") =>
+            AttributeBase::EnumLiteral(class, _, raw_type)
+                if matches!(attr_name.as_str(), "value" | "_value_")
+                    && self
+                        .get_metadata_for_class(class.class_object())
+                        .enum_metadata()
+                        .is_none_or(|enum_| enum_.mixin.is_none()) =>
             {
                 LookupResult::found_type(raw_type.clone())
             }
@@ -1157,6 +1147,12 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
                 if metadata.is_enum() && attr_name.as_str() == "name" {
                     attr_name = Name::new("_name_")
                 }
+                // Members of an enum with a data type mix-in take their values from the mix-in
+                if attr_name.as_str() == "_value_"
+                    && let Some(ty) = self.enum_mixin_value_type(class.class_object())
+                {
+                    return LookupResult::found_type(ty);
+                }
                 match self.get_instance_attribute(&class, &attr_name) {
                     Some(attr) => LookupResult::Found(attr),
                     None if metadata.has_base_any() => {
@@ -6,6 +6,7 @@
  */
 
 use dupe::Dupe;
+use itertools::Itertools;
 use ruff_python_ast::name::Name;
 use ruff_text_size::TextRange;
 use starlark_map::small_set::SmallSet;
@@ -29,6 +30,7 @@ use crate::types::callable::FuncMetadata;
 use crate::types::callable::Function;
 use crate::types::callable::FunctionKind;
 use crate::types::callable::Params;
+use crate::types::class::Class;
 use crate::types::class::ClassType;
 use crate::types::literal::Lit;
 use crate::types::type_var::Restriction;
@@ -479,6 +481,36 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
         Type::TypedDict(typed_dict)
     }
 
+    /// Report an error if `cls` has abstract methods without an implementation. We only check
+    /// calls of the class object itself: calling a value of type `type[C]` is fine, since it may
+    /// be a concrete subclass of `C` at runtime.
+    pub fn check_abstract_class_instantiation(
+        &self,
+        cls: &Class,
+        range: TextRange,
+        errors: &ErrorCollector,
+    ) {
+        let metadata = self.get_metadata_for_class(cls);
+        // Protocols get their own error when instantiated.
+        if metadata.is_abstract() && !metadata.is_protocol() && !metadata.has_base_any() {
+            self.error(
+                errors,
+                range,
+                ErrorKind::BadInstantiation,
+                None,
+                format!(
+                    "Cannot instantiate `{}` because the following members are abstract: {}",
+                    cls.name(),
+                    metadata
+                        .abstract_methods()
+                        .iter()
+                        .map(|name| format!("`{name}`"))
+                        .join(", "),
+                ),
+            );
+        }
+    }
+
     fn first_arg_type(&self, args: &[CallArg], errors: &ErrorCollector) -> Option<Type> {
         if let Some(first_arg) = args.first() {
             match first_arg {
@@ -46,7 +46,6 @@ use crate::types::callable::DataclassKeywords;
 use crate::types::callable::FuncMetadata;
 use crate::types::callable::Function;
 use crate::types::callable::FunctionKind;
-use crate::types::callable::Param;
 use crate::types::callable::Required;
 use crate::types::class::Class;
 use crate::types::class::ClassType;
@@ -176,9 +175,9 @@ impl ClassField {
         }
     }
 
-    /// Get the raw type. Only suitable for use in this module, this type may
+    /// Get the raw type. Only suitable for use in `alt::class`, this type may
     /// not correspond to the type of any actual operations on the attribute.
-    fn raw_type(&self) -> &Type {
+    pub(in crate::alt::class) fn raw_type(&self) -> &Type {
         match &self.0 {
             ClassFieldInner::Simple { ty, .. } => ty,
         }
@@ -240,19 +239,6 @@ impl ClassField {
         }
     }
 
-    pub fn as_param(self, name: &Name, default: bool, kw_only: bool) -> Param {
-        let ClassField(ClassFieldInner::Simple { ty, .. }) = self;
-        let required = match default {
-            true => Required::Optional,
-            false => Required::Required,
-        };
-        if kw_only {
-            Param::KwOnly(name.clone(), ty, required)
-        } else {
-            Param::Pos(name.clone(), ty, required)
-        }
-    }
-
     fn depends_on_class_type_parameter(&self, cls: &Class) -> bool {
         let tparams = cls.tparams();
         let mut qs = SmallSet::new();
@@ -347,6 +333,17 @@ impl ClassField {
         }
     }
 
+    /// Is this field provided by the class itself, rather than by its instances? A field with an
+    /// explicit annotation is an instance variable (possibly with a default set on the class)
+    /// unless it is annotated with `ClassVar`.
+    pub fn is_class_level(&self) -> bool {
+        if self.has_explicit_annotation() {
+            self.is_class_var()
+        } else {
+            matches!(self.initialization(), ClassFieldInitialization::Class(_))
+        }
+    }
+
     pub fn is_init_var(&self) -> bool {
         match &self.0 {
             ClassFieldInner::Simple { annotation, .. } => {
@@ -545,7 +542,7 @@ pub enum DataclassMember {
     /// A dataclass field
     Field(ClassField, BoolKeywords),
     /// A pseudo-field that only appears as a constructor argument
-    InitVar(ClassField),
+    InitVar(ClassField, BoolKeywords),
     /// A pseudo-field annotated with KW_ONLY
     KwOnlyMarker,
     /// Anything else
@@ -650,6 +647,10 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
                 .is_some_and(|m| m.elements.contains(name))
         {
             for q in &[Qualifier::Final, Qualifier::ClassVar] {
+                // `ClassVar` keys in a TypedDict are reported when computing its metadata.
+                if metadata.is_typed_dict() && *q == Qualifier::ClassVar {
+                    continue;
+                }
                 if direct_annotation.is_some_and(|ann| ann.has_qualifier(q)) {
                     self.error(
                         errors,
@@ -682,6 +683,48 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
             }
         }
 
+        // Protocols are matched structurally rather than subclassed for their implementation,
+        // so there is nothing for `@final` to protect.
+        if metadata.is_protocol() && value_ty.has_final_decoration() {
+            self.error(
+                errors,
+                range,
+                ErrorKind::BadClassDefinition,
+                None,
+                format!("Protocol method `{}` may not be marked `@final`", name),
+            );
+        }
+
+        // A method defined after an annotated dataclass field replaces the field's default value,
+        // which is almost certainly a mistake.
+        if direct_annotation.is_some()
+            && matches!(initial_value, ClassFieldInitialValue::Class(None))
+            && matches!(
+                value_ty,
+                Type::Function(_)
+                    | Type::Overload(_)
+                    | Type::Forall(box Forall {
+                        body: Forallable::Function(_),
+                        ..
+                    })
+            )
+            && metadata
+                .dataclass_metadata()
+                .is_some_and(|dataclass| dataclass.fields.contains(name))
+        {
+            self.error(
+                errors,
+                range,
+                ErrorKind::BadClassDefinition,
+                None,
+                format!(
+                    "Dataclass field `{}` has the same name as a method of `{}`",
+                    name,
+                    class.name()
+                ),
+            );
+        }
+
         // Determine whether this is an explicit `@override`.
         let is_override = value_ty.is_override();
 
@@ -744,6 +787,7 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
         // We currently skip the check for `_value_` if the class defines `__new__`, since that can
         // change the value of the enum member. https://docs.python.org/3/howto/enum.html#when-to-use-new-vs-init
         let ty = if let Some(enum_) = metadata.enum_metadata()
+            && !enum_.ignored.contains(name)
             && self.is_valid_enum_member(name, &ty, &initialization)
         {
             if direct_annotation.is_some() {
@@ -767,6 +811,13 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
                 name.clone(),
                 ty.clone(),
             ))))
+        } else if metadata.is_enum()
+            && let Type::ClassType(cls) = &ty
+            && cls.has_qname("enum", "nonmember")
+            && let [value_ty] = cls.targs().as_slice()
+        {
+            // A value wrapped in nonmember() is a plain class attribute.
+            value_ty.clone()
         } else {
             ty
         };
@@ -821,6 +872,21 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
                 errors,
             );
         }
+        if let ClassFieldInitialValue::Instance(_) = initial_value
+            && let Some(slots) = metadata.slots()
+            && !slots.contains(name)
+        {
+            self.error(
+                errors,
+                range,
+                ErrorKind::MissingAttribute,
+                None,
+                format!(
+                    "Attribute `{name}` is not declared in `__slots__` of class `{}`",
+                    class.name()
+                ),
+            );
+        }
         if let Some(method_name) = implicit_def_method {
             let mut defined_in_parent = false;
             let metadata = self.get_metadata_for_class(class);
@@ -933,7 +999,7 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
         {
             DataclassMember::NotAField // Class variables are not dataclass fields
         } else if field.is_init_var() {
-            DataclassMember::InitVar(field.clone())
+            DataclassMember::InitVar(field.clone(), field.dataclass_flags_of(kw_only))
         } else {
             DataclassMember::Field(field.clone(), field.dataclass_flags_of(kw_only))
         }
@@ -1245,7 +1311,7 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
         name: &Name,
         include_initvar: bool,
     ) -> Option<Arc<ClassField>> {
-        if cls.contains(name) {
+        if cls.contains(name) && !self.is_ignored_enum_field(cls, name) {
             let field = self.get_from_class(cls, &KeyClassField(cls.index(), name.clone()));
             if !include_initvar && field.is_init_var() {
                 None
@@ -1312,6 +1378,13 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
         self.get_class_member_impl(cls, name, false)
     }
 
+    /// Is `name` a member of `cls` that is provided by the class itself rather than by its
+    /// instances?
+    pub fn is_class_level_member(&self, cls: &Class, name: &Name) -> bool {
+        self.get_class_member(cls, name)
+            .is_some_and(|member| member.value.is_class_level())
+    }
+
     pub fn get_instance_attribute(&self, cls: &ClassType, name: &Name) -> Option<Attribute> {
         self.get_class_member(cls.class_object(), name)
             .map(|member| self.as_instance_attribute(&member.value, &Instance::of_class(cls)))
@@ -1407,6 +1480,28 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
         }
     }
 
+    /// Get the `__init_subclass__` method that is called when a class with the given metadata is
+    /// defined, as a raw callable that still takes `cls` as its first parameter. This is the first
+    /// definition in the MRO after the class itself. Returns `None` if there is no such method.
+    pub(in crate::alt::class) fn get_inherited_dunder_init_subclass(
+        &self,
+        metadata: &ClassMetadata,
+    ) -> Option<WithDefiningClass<Type>> {
+        metadata.ancestors(self.stdlib).find_map(|ancestor| {
+            let field = self.get_field_from_current_class_only(
+                ancestor.class_object(),
+                &dunder::INIT_SUBCLASS,
+                false,
+            )?;
+            let ty = Arc::unwrap_or_clone(field)
+                .as_raw_special_method_type(&Instance::of_class(ancestor))?;
+            Some(WithDefiningClass {
+                value: ty,
+                defining_class: ancestor.class_object().dupe(),
+            })
+        })
+    }
+
     /// Get the class's `__new__` method.
     ///
     /// This lookup skips normal method binding logic (it behaves like a cross
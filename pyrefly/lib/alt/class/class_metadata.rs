@@ -11,8 +11,11 @@ use std::sync::Arc;
 use dupe::Dupe;
 use itertools::Either;
 use itertools::Itertools;
+use pyrefly_util::display::commas_iter;
 use pyrefly_util::prelude::SliceExt;
 use ruff_python_ast::Expr;
+use ruff_python_ast::ExprList;
+use ruff_python_ast::ExprTuple;
 use ruff_python_ast::Identifier;
 use ruff_python_ast::name::Name;
 use ruff_text_size::Ranged;
@@ -28,15 +31,22 @@ use crate::alt::types::class_metadata::DataclassMetadata;
 use crate::alt::types::class_metadata::EnumMetadata;
 use crate::alt::types::class_metadata::NamedTupleMetadata;
 use crate::alt::types::class_metadata::ProtocolMetadata;
+use crate::alt::types::class_metadata::TotalOrderingMetadata;
 use crate::alt::types::class_metadata::TypedDictMetadata;
+use crate::binding::binding::ClassFieldInitialValue;
 use crate::binding::binding::Key;
+use crate::binding::binding::KeyClassField;
 use crate::binding::binding::KeyLegacyTypeParam;
+use crate::dunder;
 use crate::error::collector::ErrorCollector;
 use crate::error::kind::ErrorKind;
 use crate::graph::index::Idx;
 use crate::module::module_name::ModuleName;
 use crate::ruff::ast::Ast;
+use crate::types::callable::DataclassKeywords;
 use crate::types::callable::FunctionKind;
+use crate::types::callable::Param;
+use crate::types::callable::Params;
 use crate::types::class::Class;
 use crate::types::class::ClassType;
 use crate::types::literal::Lit;
@@ -44,6 +54,8 @@ use crate::types::special_form::SpecialForm;
 use crate::types::tuple::Tuple;
 use crate::types::types::AnyStyle;
 use crate::types::types::CalleeKind;
+use crate::types::types::Forall;
+use crate::types::types::Forallable;
 use crate::types::types::TParam;
 use crate::types::types::Type;
 
@@ -74,7 +86,98 @@ impl BaseClass {
     }
 }
 
+/// Names like `__x` (but not dunders like `__x__`) are mangled by Python to be private to the
+/// class that defines them, so they are implementation details rather than part of an interface.
+fn is_mangled_private_name(name: &Name) -> bool {
+    name.starts_with("__") && !name.ends_with("__")
+}
+
 impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
+    /// Whether a class defined in source (not a stub) inherits from `Generic` or `Protocol` at
+    /// runtime. Classes from stubs are skipped, since many generic stdlib classes do not actually
+    /// subclass `Generic`.
+    fn is_runtime_generic(&self, cls: &Class, metadata: &ClassMetadata) -> bool {
+        let is_source_generic =
+            |c: &Class| !c.module_info().path().is_interface() && !c.tparams().is_empty();
+        (metadata.is_protocol() && !cls.module_info().path().is_interface())
+            || is_source_generic(cls)
+            || metadata
+                .ancestors_no_object()
+                .iter()
+                .any(|a| is_source_generic(a.class_object()))
+    }
+
+    /// Check the keywords in a class definition (other than `metaclass`) against the parameters of
+    /// the `__init_subclass__` method that will receive them.
+    fn check_init_subclass_keywords(
+        &self,
+        metadata: &ClassMetadata,
+        keywords: &[(Name, TextRange, Type)],
+        errors: &ErrorCollector,
+    ) {
+        // If some ancestor is unknown, it may define an `__init_subclass__` that we can't see.
+        if keywords.is_empty() || metadata.has_base_any() {
+            return;
+        }
+        let Some(init_subclass) = self.get_inherited_dunder_init_subclass(metadata) else {
+            return;
+        };
+        let defining_class = init_subclass.defining_class.name();
+        let params = match &init_subclass.value {
+            Type::Function(func) => &func.signature.params,
+            Type::Forall(box Forall {
+                body: Forallable::Function(func),
+                ..
+            }) => &func.signature.params,
+            // We don't try to match keywords against overloads or other callables.
+            _ => return,
+        };
+        let Params::List(params) = params else {
+            return;
+        };
+        // Skip the implicit `cls` parameter.
+        let params = params.items().get(1..).unwrap_or_default();
+        for (name, range, value) in keywords {
+            let param_ty = params.iter().find_map(|p| match p {
+                Param::Pos(n, ty, _) | Param::KwOnly(n, ty, _) if n == name => Some(ty),
+                _ => None,
+            });
+            let param_ty = param_ty.or_else(|| {
+                params.iter().find_map(|p| match p {
+                    Param::Kwargs(_, ty) => Some(ty),
+                    _ => None,
+                })
+            });
+            let Some(param_ty) = param_ty else {
+                self.error(
+                    errors,
+                    *range,
+                    ErrorKind::InvalidArgument,
+                    None,
+                    format!(
+                        "Class keyword argument `{name}` is not accepted by `{defining_class}.__init_subclass__`"
+                    ),
+                );
+                continue;
+            };
+            if !param_ty.any(|ty| matches!(ty, Type::Quantified(_)))
+                && !self.is_subset_eq(value, param_ty)
+            {
+                self.error(
+                    errors,
+                    *range,
+                    ErrorKind::InvalidArgument,
+                    None,
+                    format!(
+                        "Class keyword argument `{name}` has type `{}`, which is not assignable to parameter type `{}` of `{defining_class}.__init_subclass__`",
+                        self.for_display(value.clone()),
+                        self.for_display(param_ty.clone()),
+                    ),
+                );
+            }
+        }
+    }
+
     fn new_type_base(
         &self,
         base_type_and_range: Option<(Type, TextRange)>,
@@ -111,8 +214,7 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
                         "Second argument to NewType cannot be an unbound generic".to_owned(),
                     );
                 }
-                let metadata = self.get_metadata_for_class(c.class_object());
-                Some((c, metadata))
+                Some((c, base_class_metadata))
             }
             Some((Type::Tuple(Tuple::Concrete(ts)), _)) => {
                 // TODO: we lose ordering/length information when we convert to the class representation
@@ -125,6 +227,18 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
                 let metadata = self.get_metadata_for_class(class_ty.class_object());
                 Some((class_ty, metadata))
             }
+            // The typing spec forbids a TypedDict here, since TypedDict instances are plain dicts at
+            // runtime. Note that a NewType over another NewType is allowed, and is handled above.
+            Some((Type::TypedDict(_), range)) => {
+                self.error(
+                    errors,
+                    range,
+                    ErrorKind::InvalidArgument,
+                    None,
+                    "Second argument to NewType cannot be a TypedDict".to_owned(),
+                );
+                None
+            }
             Some((_, range)) => {
                 self.error(
                     errors,
@@ -168,19 +282,41 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
         }
         let mut protocol_metadata = if bases.iter().any(|x| matches!(x, BaseClass::Protocol(_))) {
             Some(ProtocolMetadata {
-                members: cls.fields().cloned().collect(),
+                members: cls
+                    .fields()
+                    .filter(|name| !is_mangled_private_name(name))
+                    .cloned()
+                    .collect(),
                 is_runtime_checkable: false,
+                has_data_members: cls
+                    .fields()
+                    .any(|name| !is_mangled_private_name(name) && cls.is_field_annotated(name)),
+                class_vars: cls
+                    .fields()
+                    .filter(|name| cls.is_field_class_var(name))
+                    .cloned()
+                    .collect(),
             })
         } else {
             None
         };
         let mut has_base_any = false;
         let mut has_generic_base_class = false;
-        let bases_with_metadata = bases
+        // The first `Generic` or `Protocol` marker in the base list, if we have passed one.
+        let mut generic_marker = None;
+        let (bases_with_metadata, base_ranges) = bases
             .iter()
             .filter_map(|x| {
+                match x {
+                    BaseClass::Generic(_) if generic_marker.is_none() => generic_marker = Some("Generic"),
+                    BaseClass::Protocol(_) if generic_marker.is_none() => generic_marker = Some("Protocol"),
+                    _ => {}
+                }
                 let base_type_and_range = match x {
-                    BaseClass::Expr(x) => Some((self.expr_untype(x, TypeFormContext::BaseClassList, errors), x.range())),
+                    BaseClass::Expr(x) => {
+                        self.check_bare_generic_alias_base(x, errors);
+                        Some((self.expr_untype(x, TypeFormContext::BaseClassList, errors), x.range()))
+                    }
                     BaseClass::TypedDict => {
                         is_typed_dict = true;
                         None
@@ -194,8 +330,17 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
                     }
                     _ => None,
                 };
-                if is_new_type {
-                    self.new_type_base(base_type_and_range, cls.range(), errors)
+                // Keep track of where each base came from, since some entries in the base list
+                // (such as `Generic` or `Any`) do not produce a base class.
+                let base_range = base_type_and_range.as_ref().map_or(cls.range(), |(_, range)| *range);
+                let base = if is_new_type {
+                    let base = self.new_type_base(base_type_and_range, cls.range(), errors);
+                    if let Some((_, base_class_metadata)) = &base
+                        && base_class_metadata.has_base_any()
+                    {
+                        has_base_any = true;
+                    }
+                    base
                 } else {
                     match base_type_and_range {
                         Some((Type::ClassType(c), range)) => {
@@ -215,6 +360,18 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
                                     format!("Cannot extend final class `{}`", base_cls.name()),
                                 );
                             }
+                            if let Some(marker) = generic_marker
+                                && self.is_runtime_generic(base_cls, &base_class_metadata)
+                            {
+                                // At runtime, `Generic` and `Protocol` are ancestors of this base, so listing them
+                                // first makes the MRO inconsistent.
+                                self.error(errors,
+                                    range,
+                                    ErrorKind::InvalidInheritance,
+                                    None,
+                                    format!("Base class `{}` must be listed before `{marker}`", base_cls.name()),
+                                );
+                            }
                            if base_class_metadata.is_new_type() {
                                 self.error(
                                     errors,
@@ -239,15 +396,21 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
                             if let Some(proto) = &mut protocol_metadata {
                                 if let Some(base_proto) = base_class_metadata.protocol_metadata() {
                                     proto.members.extend(base_proto.members.iter().cloned());
+                                    proto.has_data_members |= base_proto.has_data_members;
+                                    proto
+                                        .class_vars
+                                        .extend(base_proto.class_vars.iter().cloned());
                                     if base_proto.is_runtime_checkable {
                                         proto.is_runtime_checkable = true;
                                     }
-                                } else {
+                                } else if !base_cls.is_builtin("object") {
+                                    // `Generic[...]` and `Protocol[...]` are not class types, and `Any`
+                                    // bases are tracked by `has_base_any`, so neither reaches here.
                                     self.error(errors,
                                         range,
                                         ErrorKind::InvalidInheritance,
                                         None,
-                                        "If `Protocol` is included as a base class, all other bases must be protocols".to_owned(),
+                                        format!("If `Protocol` is included as a base class, all other bases must be protocols, but `{}` is not a protocol", base_cls.name()),
                                     );
                                 }
                             }
@@ -297,9 +460,10 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
                         }
                         None => None,
                     }
-                }
+                };
+                base.map(|base| (base, base_range))
             })
-            .collect::<Vec<_>>();
+            .unzip::<_, _, Vec<_>, Vec<_>>();
         if named_tuple_metadata.is_some() && bases_with_metadata.len() > 1 {
             self.error(
                 errors,
@@ -309,15 +473,15 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
                 "Named tuples do not support multiple inheritance".to_owned(),
             );
         }
-        let (metaclasses, keywords): (Vec<_>, Vec<(_, _)>) =
+        let (metaclasses, keywords): (Vec<_>, Vec<(_, _, _)>) =
             keywords.iter().partition_map(|(n, x)| match n.as_str() {
                 "metaclass" => Either::Left(x),
-                _ => Either::Right((n.clone(), self.expr_infer(x, errors))),
+                _ => Either::Right((n.clone(), x.range(), self.expr_infer(x, errors))),
             });
         let typed_dict_metadata = if is_typed_dict {
             // Validate that only 'total' keyword is allowed for TypedDict and determine is_total
             let mut is_total = true;
-            for (name, value) in &keywords {
+            for (name, _, value) in &keywords {
                 if name.as_str() != "total" {
                     self.error(
                         errors,
@@ -333,8 +497,12 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
                     is_total = false;
                 }
             }
-            let fields =
-                self.calculate_typed_dict_metadata_fields(cls, &bases_with_metadata, is_total);
+            let fields = self.calculate_typed_dict_metadata_fields(
+                cls,
+                &bases_with_metadata,
+                is_total,
+                errors,
+            );
             Some(TypedDictMetadata { fields })
         } else {
             None
@@ -343,14 +511,15 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
             .iter()
             .filter_map(|(b, metadata)| metadata.metaclass().map(|m| (b.name(), m)))
             .collect::<Vec<_>>();
-        let metaclass = self.calculate_metaclass(
-            cls,
-            metaclasses.into_iter().next(),
-            &base_metaclasses,
-            errors,
-        );
+        let raw_metaclass = metaclasses.into_iter().next();
+        let metaclass = self.calculate_metaclass(cls, raw_metaclass, &base_metaclasses, errors);
         if let Some(metaclass) = &metaclass {
             self.check_base_class_metaclasses(cls, metaclass, &base_metaclasses, errors);
+            if protocol_metadata.is_some()
+                && let Some(raw_metaclass) = raw_metaclass
+            {
+                self.check_protocol_metaclass(cls, metaclass, raw_metaclass.range(), errors);
+            }
             if self.is_subset_eq(
                 &Type::ClassType(metaclass.clone()),
                 &Type::ClassType(self.stdlib.enum_meta().clone()),
@@ -376,6 +545,9 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
                             &Type::ClassType(self.stdlib.enum_flag().clone()),
                         )
                     }),
+                    mixin: self.enum_mixin(cls, &bases_with_metadata, errors),
+                    checks: SmallSet::new(),
+                    ignored: self.enum_ignored_names(cls),
                 })
             }
             if is_typed_dict {
@@ -404,9 +576,27 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
                 );
             }
         }
+        // A custom metaclass may consume class keywords itself, so we only check them against
+        // `__init_subclass__` when the metaclass is `type`.
+        let check_init_subclass_keywords = !is_typed_dict
+            && metaclass
+                .as_ref()
+                .is_none_or(|m| m == self.stdlib.builtins_type());
         let mut is_final = false;
-        for decorator in decorators {
-            let decorator = self.get_idx(*decorator);
+        let mut is_total_ordering = false;
+        // Only direct applications count here: a protocol may also be runtime-checkable
+        // because one of its bases is.
+        let mut has_runtime_checkable_decorator = false;
+        for decorator_idx in decorators {
+            let decorator = self.get_idx(*decorator_idx);
+            if let Type::ClassType(c) = decorator.ty()
+                && c.has_qname("enum", "verify")
+                && let Some(enum_metadata) = &mut enum_metadata
+            {
+                enum_metadata
+                    .checks
+                    .extend(self.enum_verify_checks(*decorator_idx));
+            }
             match decorator.ty().callee_kind() {
                 Some(CalleeKind::Function(FunctionKind::Dataclass(kws))) => {
                     let dataclass_fields = self.get_dataclass_fields(cls, &bases_with_metadata);
@@ -416,10 +606,24 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
                     });
                 }
                 Some(CalleeKind::Function(FunctionKind::Final)) => {
+                    if is_final {
+                        self.error_redundant_class_decorator(*decorator_idx, "final", errors);
+                    }
                     is_final = true;
                 }
+                Some(CalleeKind::Function(FunctionKind::TotalOrdering)) => {
+                    is_total_ordering = true;
+                }
                 Some(CalleeKind::Function(FunctionKind::RuntimeCheckable)) => {
                     if let Some(proto) = &mut protocol_metadata {
+                        if has_runtime_checkable_decorator {
+                            self.error_redundant_class_decorator(
+                                *decorator_idx,
+                                "runtime_checkable",
+                                errors,
+                            );
+                        }
+                        has_runtime_checkable_decorator = true;
                         proto.is_runtime_checkable = true;
                     } else {
                         self.error(
@@ -434,28 +638,45 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
                 _ => {}
             }
         }
+        let total_ordering_metadata = if is_total_ordering {
+            self.total_ordering_metadata(
+                cls,
+                &bases_with_metadata,
+                dataclass_metadata.as_ref(),
+                errors,
+            )
+        } else {
+            None
+        };
+        let slots = self.class_slots(cls, &bases_with_metadata);
         if is_typed_dict
-            && let Some(bad) = bases_with_metadata.iter().find(|x| !x.1.is_typed_dict())
+            && let Some(bad) = bases_with_metadata
+                .iter()
+                .position(|x| !x.1.is_typed_dict())
         {
             self.error(errors,
-                cls.range(),
+                base_ranges[bad],
                 ErrorKind::InvalidInheritance,
                 None,
-                format!("`{}` is not a typed dictionary. Typed dictionary definitions may only extend other typed dictionaries.", bad.0),
+                format!("`{}` is not a typed dictionary. Typed dictionary definitions may only extend other typed dictionaries.", bases_with_metadata[bad].0),
             );
         }
-        let bases_with_metadata = if is_typed_dict && bases_with_metadata.is_empty() {
+        let (bases_with_metadata, base_ranges) = if is_typed_dict && bases_with_metadata.is_empty()
+        {
             // This is a "fallback" class that contains attributes that are available on all TypedDict subclasses.
             // Note that this also makes those attributes available on *instances* of said subclasses; this is
             // desirable for methods but problematic for fields like `__total__` that should be available on the class
             // but not the instance. For now, we make all fields available on both classes and instances.
             let td_fallback = self.stdlib.typed_dict_fallback();
-            vec![(
-                td_fallback.clone(),
-                self.get_metadata_for_class(td_fallback.class_object()),
-            )]
+            (
+                vec![(
+                    td_fallback.clone(),
+                    self.get_metadata_for_class(td_fallback.class_object()),
+                )],
+                vec![cls.range()],
+            )
         } else {
-            bases_with_metadata
+            (bases_with_metadata, base_ranges)
         };
         // We didn't find any type parameters for this class, but it may have ones we don't know about if:
         // - the class inherits from Any, or
@@ -463,22 +684,32 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
         //   arguments because we found an error in them.
         let has_unknown_tparams =
             cls.tparams().is_empty() && (has_base_any || has_generic_base_class);
-        ClassMetadata::new(
+        let metadata = ClassMetadata::new(
             cls,
             bases_with_metadata,
+            &base_ranges,
             metaclass,
-            keywords,
+            keywords
+                .iter()
+                .map(|(name, _, value)| (name.clone(), value.clone()))
+                .collect(),
             typed_dict_metadata,
             named_tuple_metadata,
             enum_metadata,
             protocol_metadata,
             dataclass_metadata,
+            total_ordering_metadata,
+            slots,
             has_base_any,
             is_new_type,
             is_final,
             has_unknown_tparams,
             errors,
-        )
+        );
+        if check_init_subclass_keywords {
+            self.check_init_subclass_keywords(&metadata, &keywords, errors);
+        }
+        metadata
     }
 
     fn calculate_typed_dict_metadata_fields(
@@ -486,6 +717,7 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
         cls: &Class,
         bases_with_metadata: &[(ClassType, Arc<ClassMetadata>)],
         is_total: bool,
+        errors: &ErrorCollector,
     ) -> SmallMap<Name, bool> {
         let mut all_fields = SmallMap::new();
         for (_, metadata) in bases_with_metadata.iter().rev() {
@@ -494,13 +726,181 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
             }
         }
         for name in cls.fields() {
-            if cls.is_field_annotated(name) {
+            if cls.is_field_class_var(name) {
+                self.error(
+                    errors,
+                    cls.field_decl_range(name).unwrap_or_else(|| cls.range()),
+                    ErrorKind::BadTypedDict,
+                    None,
+                    format!("TypedDict key `{}` may not be a `ClassVar`", name),
+                );
+            } else if cls.is_field_annotated(name) {
                 all_fields.insert(name.clone(), is_total);
             }
         }
         all_fields
     }
 
+    /// The instance attributes allowed by the `__slots__` of `cls` and its bases. Instances get a
+    /// `__dict__`, lifting the restriction, if `__slots__` is missing or includes `"__dict__"`, or
+    /// if any base other than `object` lacks `__slots__`. We also give up on a `__slots__` that
+    /// isn't a literal string or a list or tuple of literal strings.
+    fn class_slots(
+        &self,
+        cls: &Class,
+        bases_with_metadata: &[(ClassType, Arc<ClassMetadata>)],
+    ) -> Option<SmallSet<Name>> {
+        if !cls.contains(&dunder::SLOTS) {
+            return None;
+        }
+        let field = self.bindings().get(
+            self.bindings()
+                .key_to_idx(&KeyClassField(cls.index(), dunder::SLOTS)),
+        );
+        let ClassFieldInitialValue::Class(Some(value)) = &field.initial_value else {
+            return None;
+        };
+        let as_slot = |x: &Expr| match x {
+            Expr::StringLiteral(x) => Some(Name::new(x.value.to_str())),
+            _ => None,
+        };
+        let mut slots = match value {
+            Expr::StringLiteral(_) => SmallSet::from_iter(as_slot(value)),
+            Expr::List(ExprList { elts, .. }) | Expr::Tuple(ExprTuple { elts, .. }) => {
+                elts.iter().map(as_slot).collect::<Option<SmallSet<_>>>()?
+            }
+            _ => return None,
+        };
+        if slots.contains(&Name::new_static("__dict__")) {
+            return None;
+        }
+        for (base, base_metadata) in bases_with_metadata {
+            if !base.is_builtin("object") {
+                slots.extend(base_metadata.slots()?.iter().cloned());
+            }
+        }
+        Some(slots)
+    }
+
+    /// A generic type alias like `MyList = list[T]` used as a base without type arguments leaves its
+    /// type variables unbound, so we report it rather than silently filling in `Any`.
+    fn check_bare_generic_alias_base(&self, base: &Expr, errors: &ErrorCollector) {
+        if !matches!(base, Expr::Name(_) | Expr::Attribute(_)) {
+            return;
+        }
+        if let Type::Forall(forall) = self.expr_infer(base, &self.error_swallower())
+            && let Forallable::TypeAlias(alias) = &forall.body
+        {
+            self.error(
+                errors,
+                base.range(),
+                ErrorKind::InvalidInheritance,
+                None,
+                format!(
+                    "Generic type alias `{}` used as a base class without type arguments, leaving {} unbound",
+                    alias.name,
+                    commas_iter(|| forall.tparams.iter().map(|p| format!("`{}`", p.name()))),
+                ),
+            );
+        }
+    }
+
+    /// `functools.total_ordering` fills in the comparison methods that a class is missing, deriving
+    /// them from the first of `__lt__`, `__le__`, `__gt__` and `__ge__` that the class (or a base
+    /// other than `object`) defines.
+    fn total_ordering_metadata(
+        &self,
+        cls: &Class,
+        bases_with_metadata: &[(ClassType, Arc<ClassMetadata>)],
+        dataclass_metadata: Option<&DataclassMetadata>,
+        errors: &ErrorCollector,
+    ) -> Option<TotalOrderingMetadata> {
+        if dataclass_metadata.is_some_and(|d| d.kws.is_set(&DataclassKeywords::ORDER)) {
+            // The dataclass already synthesizes all of the comparison methods.
+            return None;
+        }
+        let is_defined = |name: &Name| {
+            cls.contains(name)
+                || bases_with_metadata.iter().any(|(base, metadata)| {
+                    base.class_object().contains(name)
+                        || metadata
+                            .ancestors_no_object()
+                            .iter()
+                            .any(|ancestor| ancestor.class_object().contains(name))
+                })
+        };
+        let (defined, missing): (Vec<_>, Vec<_>) = [dunder::LT, dunder::LE, dunder::GT, dunder::GE]
+            .into_iter()
+            .partition(|name| is_defined(name));
+        match defined.into_iter().next() {
+            Some(root) => Some(TotalOrderingMetadata {
+                root,
+                synthesized: missing,
+            }),
+            None => {
+                self.error(
+                    errors,
+                    cls.range(),
+                    ErrorKind::InvalidArgument,
+                    None,
+                    format!(
+                        "Class `{}` must define at least one ordering method (`__lt__`, `__le__`, `__gt__`, or `__ge__`) to use `total_ordering`",
+                        cls.name()
+                    ),
+                );
+                None
+            }
+        }
+    }
+
+    /// Find the concrete data type mixed into an enum, e.g. `int` for `IntEnum` or `bytes` for
+    /// `class E(bytes, Enum)`. This follows the runtime: a non-enum base is a data type if it (or
+    /// one of its ancestors) defines `__new__`, and enum bases contribute their own data type.
+    fn enum_mixin(
+        &self,
+        cls: &Class,
+        bases_with_metadata: &[(ClassType, Arc<ClassMetadata>)],
+        errors: &ErrorCollector,
+    ) -> Option<ClassType> {
+        let mut mixins: Vec<ClassType> = Vec::new();
+        for (base, metadata) in bases_with_metadata {
+            let mixin = if let Some(enum_metadata) = metadata.enum_metadata() {
+                enum_metadata.mixin.clone()
+            } else if base.class_object().contains(&dunder::NEW)
+                || metadata
+                    .ancestors_no_object()
+                    .iter()
+                    .any(|ancestor| ancestor.class_object().contains(&dunder::NEW))
+            {
+                Some(base.clone())
+            } else {
+                None
+            };
+            if let Some(mixin) = mixin
+                && !mixins.contains(&mixin)
+            {
+                mixins.push(mixin);
+            }
+        }
+        if mixins.len() > 1 {
+            self.error(
+                errors,
+                cls.range(),
+                ErrorKind::InvalidInheritance,
+                None,
+                format!(
+                    "Enum `{}` has multiple data type mix-ins: {}",
+                    cls.name(),
+                    mixins
+                        .iter()
+                        .map(|mixin| format!("`{}`", self.for_display(mixin.clone().to_type())))
+                        .join(", ")
+                ),
+            );
+        }
+        mixins.into_iter().next()
+    }
+
     /// This helper deals with special cases where we want to intercept an `Expr`
     /// manually and create a special variant of `BaseClass` instead of calling
     /// `expr_untype` and creating a `BaseClass::Type`.
@@ -543,23 +943,63 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
         {
             // This branch handles `Generic[...]` and `Protocol[...]`
             let mut type_var_tuple_count = 0;
-            let args = Ast::unpack_slice(&subscript.slice).map(|x| {
+            let mut param_spec_count = 0;
+            let unpacked = Ast::unpack_slice(&subscript.slice);
+            if unpacked.is_empty() {
+                let base = match special_base_class {
+                    BaseClass::Protocol(_) => "Protocol",
+                    _ => "Generic",
+                };
+                self.error(
+                    errors,
+                    subscript.slice.range(),
+                    ErrorKind::BadSpecialization,
+                    None,
+                    format!("Expected at least one type argument for `{base}`"),
+                );
+            }
+            let args = unpacked.map(|x| {
                 let ty = self.expr_untype(x, TypeFormContext::GenericBase, errors);
-                if let Type::Unpack(unpacked) = &ty
-                    && unpacked.is_kind_type_var_tuple()
-                {
-                    if type_var_tuple_count == 1 {
-                        self.error(
-                            errors,
-                            x.range(),
-                            ErrorKind::InvalidInheritance,
-                            None,
-                            "There cannot be more than one TypeVarTuple type parameter".to_owned(),
-                        );
+                match &ty {
+                    Type::ParamSpec(_) | Type::Quantified(_) if ty.is_kind_param_spec() => {
+                        if param_spec_count == 1 {
+                            self.error(
+                                errors,
+                                x.range(),
+                                ErrorKind::InvalidInheritance,
+                                None,
+                                "There cannot be more than one ParamSpec type parameter".to_owned(),
+                            );
+                        }
+                        param_spec_count += 1;
+                        ty
                     }
-                    type_var_tuple_count += 1;
+                    Type::Unpack(unpacked) if unpacked.is_kind_type_var_tuple() => {
+                        if type_var_tuple_count == 1 {
+                            self.error(
+                                errors,
+                                x.range(),
+                                ErrorKind::InvalidInheritance,
+                                None,
+                                "There cannot be more than one TypeVarTuple type parameter"
+                                    .to_owned(),
+                            );
+                        }
+                        type_var_tuple_count += 1;
+                        ty
+                    }
+                    Type::Unpack(unpacked) => self.error(
+                        errors,
+                        x.range(),
+                        ErrorKind::InvalidTypeVarTuple,
+                        None,
+                        format!(
+                            "`Unpack` in a generic base must be applied to a TypeVarTuple, got `{}`",
+                            self.for_display((**unpacked).clone()),
+                        ),
+                    ),
+                    _ => ty,
                 }
-                ty
             });
             special_base_class.apply(args);
             special_base_class
@@ -586,7 +1026,7 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
             .map(|p| (p.quantified.clone(), p))
             .collect::<SmallMap<_, _>>();
 
-        let lookup_tparam = |t: &Type| {
+        let lookup_tparam = |t: &Type, base: &str| {
             let (q, kind) = match t {
                 Type::Unpack(t) => (t.as_quantified(), "TypeVarTuple"),
                 _ => (t.as_quantified(), "type variable"),
@@ -597,7 +1037,10 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
                     name.range,
                     ErrorKind::InvalidTypeVar,
                     None,
-                    format!("Expected a {kind}, got `{}`", self.for_display(t.clone())),
+                    format!(
+                        "Expected a {kind} as an argument to `{base}`, got `{}`",
+                        self.for_display(t.clone())
+                    ),
                 );
             }
             q.and_then(|q| {
@@ -615,6 +1058,20 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
             })
         };
 
+        let insert_tparam = |tparams: &mut SmallSet<TParam>, p: TParam, base: &str| {
+            if tparams.contains(&p) {
+                self.error(
+                    errors,
+                    name.range,
+                    ErrorKind::InvalidTypeVar,
+                    None,
+                    format!("Duplicate type variable `{}` in `{base}`", p.name()),
+                );
+            } else {
+                tparams.insert(p);
+            }
+        };
+
         // TODO(stroxler): There are a lot of checks, such as that `Generic` only appears once
         // and no non-type-vars are used, that we can more easily detect in a dedictated class
         // validation step that validates all the bases. We are deferring these for now.
@@ -624,15 +1081,15 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
             match base {
                 BaseClass::Generic(ts) => {
                     for t in ts {
-                        if let Some(p) = lookup_tparam(t) {
-                            generic_tparams.insert(p);
+                        if let Some(p) = lookup_tparam(t, "Generic") {
+                            insert_tparam(&mut generic_tparams, p, "Generic");
                         }
                     }
                 }
                 BaseClass::Protocol(ts) if !ts.is_empty() => {
                     for t in ts {
-                        if let Some(p) = lookup_tparam(t) {
-                            protocol_tparams.insert(p);
+                        if let Some(p) = lookup_tparam(t, "Protocol") {
+                            insert_tparam(&mut protocol_tparams, p, "Protocol");
                         }
                     }
                 }
@@ -696,6 +1153,7 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
                 let m = (*m).clone();
                 let accept_m = match &inherited_meta {
                     None => true,
+                    Some(inherited) if *inherited == m => false,
                     Some(inherited) => self.is_subset_eq(
                         &Type::ClassType(m.clone()),
                         &Type::ClassType(inherited.clone()),
@@ -709,6 +1167,60 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
         }
     }
 
+    /// `Protocol` classes use `typing._ProtocolMeta`, a subclass of `ABCMeta`, so an explicit
+    /// metaclass on a protocol is either redundant (`ABCMeta` itself) or must subclass `ABCMeta`.
+    fn check_protocol_metaclass(
+        &self,
+        cls: &Class,
+        metaclass: &ClassType,
+        range: TextRange,
+        errors: &ErrorCollector,
+    ) {
+        if metaclass.has_qname("abc", "ABCMeta") {
+            self.error(
+                errors,
+                range,
+                ErrorKind::BadClassDefinition,
+                None,
+                format!(
+                    "Protocol `{}` does not need `metaclass=ABCMeta`, since protocols already use a subclass of `ABCMeta`",
+                    cls.name(),
+                ),
+            );
+        } else if !self
+            .get_metadata_for_class(metaclass.class_object())
+            .ancestors(self.stdlib)
+            .any(|ancestor| ancestor.has_qname("abc", "ABCMeta"))
+        {
+            self.error(
+                errors,
+                range,
+                ErrorKind::InvalidInheritance,
+                None,
+                format!(
+                    "Metaclass of protocol `{}` has type `{}` which is not a subclass of `ABCMeta`",
+                    cls.name(),
+                    self.for_display(Type::ClassType(metaclass.clone())),
+                ),
+            );
+        }
+    }
+
+    fn error_redundant_class_decorator(
+        &self,
+        decorator_idx: Idx<Key>,
+        name: &str,
+        errors: &ErrorCollector,
+    ) {
+        self.error(
+            errors,
+            self.bindings().idx_to_key(decorator_idx).range(),
+            ErrorKind::InvalidArgument,
+            None,
+            format!("Redundant `@{name}` decorator, it is already applied to this class"),
+        );
+    }
+
     fn check_base_class_metaclasses(
         &self,
         cls: &Class,
@@ -720,12 +1232,24 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
         // specified directly or through inheritance) is not a subtype of all
         // base class metaclasses.
         let metaclass_type = Type::ClassType(metaclass.clone());
+        // Bases commonly share a metaclass, so only check each distinct one once.
+        let mut is_compatible: SmallMap<&ClassType, bool> = SmallMap::new();
         for (base_name, m) in base_metaclasses {
             let base_metaclass_type = Type::ClassType((*m).clone());
-            if !self
-                .solver()
-                .is_subset_eq(&metaclass_type, &base_metaclass_type, self.type_order())
-            {
+            let compatible = match is_compatible.get(*m) {
+                Some(compatible) => *compatible,
+                None => {
+                    let compatible = *m == metaclass
+                        || self.solver().is_subset_eq(
+                            &metaclass_type,
+                            &base_metaclass_type,
+                            self.type_order(),
+                        );
+                    is_compatible.insert(*m, compatible);
+                    compatible
+                }
+            };
+            if !compatible {
                 self.error(errors,
                     cls.range(),
                     ErrorKind::InvalidInheritance,
@@ -34,7 +34,52 @@ use crate::types::tuple::Tuple;
 use crate::types::types::AnyStyle;
 use crate::types::types::Type;
 
+/// A parameter of the `__init__` method synthesized for a dataclass.
+#[derive(Debug, Clone)]
+pub struct DataclassFieldType {
+    pub name: Name,
+    pub ty: Type,
+    pub has_default: bool,
+    pub kw_only: bool,
+}
+
+impl DataclassFieldType {
+    fn into_param(self) -> Param {
+        let required = if self.has_default {
+            Required::Optional
+        } else {
+            Required::Required
+        };
+        if self.kw_only {
+            Param::KwOnly(self.name, self.ty, required)
+        } else {
+            Param::Pos(self.name, self.ty, required)
+        }
+    }
+}
+
 impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
+    /// The parameters of the `__init__` method synthesized for a dataclass, in order, or `None` if
+    /// `cls` is not a dataclass. This includes `InitVar` pseudo-fields and excludes fields with
+    /// `init=False`.
+    pub fn dataclass_field_types(&self, cls: &Class) -> Option<Vec<DataclassFieldType>> {
+        let metadata = self.get_metadata_for_class(cls);
+        let dataclass = metadata.dataclass_metadata()?;
+        let class_kw_only = dataclass.kws.is_set(&DataclassKeywords::KW_ONLY);
+        Some(
+            self.iter_fields(cls, &dataclass.fields, true)
+                .into_iter()
+                .filter(|(_, _, flags)| flags.is_set(&DataclassKeywords::INIT))
+                .map(|(name, field, flags)| DataclassFieldType {
+                    ty: field.raw_type().clone(),
+                    name,
+                    has_default: flags.is_set(&DataclassKeywords::DEFAULT),
+                    kw_only: class_kw_only || flags.is_set(&DataclassKeywords::KW_ONLY),
+                })
+                .collect(),
+        )
+    }
+
     /// Gets dataclass fields for an `@dataclass`-decorated class.
     pub fn get_dataclass_fields(
         &self,
@@ -59,15 +104,10 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
         let metadata = self.get_metadata_for_class(cls);
         let dataclass = metadata.dataclass_metadata()?;
         let mut fields = SmallMap::new();
-        if dataclass.kws.is_set(&DataclassKeywords::INIT) {
-            fields.insert(
-                dunder::INIT,
-                self.get_dataclass_init(
-                    cls,
-                    &dataclass.fields,
-                    dataclass.kws.is_set(&DataclassKeywords::KW_ONLY),
-                ),
-            );
+        if dataclass.kws.is_set(&DataclassKeywords::INIT)
+            && let Some(init_fields) = self.dataclass_field_types(cls)
+        {
+            fields.insert(dunder::INIT, self.get_dataclass_init(cls, init_fields));
         }
         let dataclass_fields_type = self.stdlib.dict(
             self.stdlib.str().clone().to_type(),
@@ -120,9 +160,9 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
                 }
                 DataclassMember::NotAField => None,
                 DataclassMember::Field(field, keywords) => Some((name.clone(), field, keywords)),
-                DataclassMember::InitVar(field) => {
+                DataclassMember::InitVar(field, keywords) => {
                     if include_initvar {
-                        Some((name.clone(), field, BoolKeywords::new()))
+                        Some((name.clone(), field, keywords))
                     } else {
                         None
                     }
@@ -135,19 +175,10 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
     fn get_dataclass_init(
         &self,
         cls: &Class,
-        fields: &SmallSet<Name>,
-        kw_only: bool,
+        init_fields: Vec<DataclassFieldType>,
     ) -> ClassSynthesizedField {
         let mut params = vec![self.class_self_param(cls, false)];
-        for (name, field, field_flags) in self.iter_fields(cls, fields, true) {
-            if field_flags.is_set(&DataclassKeywords::INIT) {
-                params.push(field.as_param(
-                    &name,
-                    field_flags.is_set(&DataclassKeywords::DEFAULT),
-                    kw_only || field_flags.is_set(&DataclassKeywords::KW_ONLY),
-                ));
-            }
-        }
+        params.extend(init_fields.into_iter().map(DataclassFieldType::into_param));
         let ty = Type::Function(Box::new(Function {
             signature: Callable::list(ParamList::new(params), Type::None),
             metadata: FuncMetadata::def(
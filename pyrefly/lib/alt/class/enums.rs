@@ -7,16 +7,31 @@
 
 use std::sync::Arc;
 
+use pyrefly_util::prelude::SliceExt;
+use ruff_python_ast::Expr;
+use ruff_python_ast::ExprList;
+use ruff_python_ast::ExprTuple;
 use ruff_python_ast::name::Name;
 use starlark_map::small_set::SmallSet;
 
 use crate::alt::answers::AnswersSolver;
 use crate::alt::answers::LookupAnswer;
 use crate::alt::class::class_field::ClassFieldInitialization;
+use crate::alt::types::class_metadata::EnumCheck;
+use crate::binding::binding::Binding;
+use crate::binding::binding::ClassFieldInitialValue;
+use crate::binding::binding::Key;
+use crate::binding::binding::KeyClassField;
+use crate::error::collector::ErrorCollector;
+use crate::error::kind::ErrorKind;
+use crate::graph::index::Idx;
 use crate::types::class::Class;
 use crate::types::literal::Lit;
 use crate::types::types::Type;
 
+/// The most missing values listed in a `CONTINUOUS` verification error.
+const MAX_REPORTED_VALUES: usize = 10;
+
 impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
     pub fn get_enum_member(&self, cls: &Class, name: &Name) -> Option<Lit> {
         self.get_field_from_current_class_only(cls, name, false)
@@ -29,6 +44,205 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
             .collect()
     }
 
+    /// The type of `_value_` for an enum with a data type mix-in, e.g. `bytes` for
+    /// `class E(bytes, Enum)`. Returns `None` if there is no mix-in, or if some class below
+    /// `enum.Enum` declares `_value_` itself (as `IntEnum` does), in which case that declaration wins.
+    pub fn enum_mixin_value_type(&self, cls: &Class) -> Option<Type> {
+        let mixin = self
+            .get_metadata_for_class(cls)
+            .enum_metadata()?
+            .mixin
+            .clone()?;
+        match self.get_class_member(cls, &Name::new_static("_value_")) {
+            Some(member) if !member.defining_class.has_qname("enum", "Enum") => None,
+            _ => Some(mixin.to_type()),
+        }
+    }
+
+    /// The checks requested by an `@enum.verify(...)` class decorator. The arguments are
+    /// recognized by name, so both `UNIQUE` and `EnumCheck.UNIQUE` work.
+    pub fn enum_verify_checks(&self, decorator: Idx<Key>) -> SmallSet<EnumCheck> {
+        let Binding::Decorator(Expr::Call(call)) = self.bindings().get(decorator) else {
+            return SmallSet::new();
+        };
+        call.arguments
+            .args
+            .iter()
+            .filter_map(|arg| {
+                let name = match arg {
+                    Expr::Name(x) => &x.id,
+                    Expr::Attribute(x) => &x.attr.id,
+                    _ => return None,
+                };
+                match name.as_str() {
+                    "UNIQUE" => Some(EnumCheck::Unique),
+                    "CONTINUOUS" => Some(EnumCheck::Continuous),
+                    "NAMED_FLAGS" => Some(EnumCheck::NamedFlags),
+                    _ => None,
+                }
+            })
+            .collect()
+    }
+
+    /// Check the members of an enum against the constraints requested with `@enum.verify(...)`.
+    /// Only members with literal values can be checked.
+    pub fn check_enum_verify(&self, cls: &Class, errors: &ErrorCollector) {
+        let metadata = self.get_metadata_for_class(cls);
+        let Some(enum_metadata) = metadata.enum_metadata() else {
+            return;
+        };
+        if enum_metadata.checks.is_empty() {
+            return;
+        }
+        let members = self
+            .get_enum_members(cls)
+            .into_iter()
+            .filter_map(|lit| match lit {
+                Lit::Enum(box (_, name, value @ Type::Literal(_))) => Some((name, value)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let int_values = members
+            .iter()
+            .filter_map(|(name, value)| match value {
+                Type::Literal(lit) => Some((name, lit.as_index_i64()?)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let error = |name: Option<&Name>, msg: String| {
+            let range = name
+                .and_then(|name| cls.field_decl_range(name))
+                .unwrap_or_else(|| cls.range());
+            self.error(errors, range, ErrorKind::BadClassDefinition, None, msg);
+        };
+        for check in &enum_metadata.checks {
+            match check {
+                EnumCheck::Unique => {
+                    for (i, (name, value)) in members.iter().enumerate() {
+                        if let Some((canonical, _)) = members[..i].iter().find(|(_, v)| v == value)
+                        {
+                            error(
+                                Some(name),
+                                format!(
+                                    "Enum member `{name}` is an alias of `{canonical}`, but `{}` is verified as `UNIQUE`",
+                                    cls.name()
+                                ),
+                            );
+                        }
+                    }
+                }
+                EnumCheck::Continuous => {
+                    let missing = if enum_metadata.is_flag {
+                        // Flags must use every bit between their lowest and highest single-bit values.
+                        let bits = int_values
+                            .iter()
+                            .filter(|(_, v)| *v > 0 && v.count_ones() == 1)
+                            .map(|(_, v)| v.trailing_zeros())
+                            .collect::<SmallSet<_>>();
+                        match (bits.iter().min(), bits.iter().max()) {
+                            (Some(lo), Some(hi)) => (*lo..*hi)
+                                .filter(|b| !bits.contains(b))
+                                .map(|b| 1i64 << b)
+                                .collect::<Vec<_>>(),
+                            _ => Vec::new(),
+                        }
+                    } else {
+                        let mut values = int_values.map(|(_, v)| *v);
+                        values.sort();
+                        values.dedup();
+                        values
+                            .windows(2)
+                            .flat_map(|w| (w[0] + 1)..w[1])
+                            .take(MAX_REPORTED_VALUES + 1)
+                            .collect::<Vec<_>>()
+                    };
+                    if !missing.is_empty() {
+                        let mut shown = missing
+                            .iter()
+                            .take(MAX_REPORTED_VALUES)
+                            .map(|v| v.to_string())
+                            .collect::<Vec<_>>();
+                        if missing.len() > MAX_REPORTED_VALUES {
+                            shown.push("...".to_owned());
+                        }
+                        error(
+                            None,
+                            format!(
+                                "Enum `{}` is verified as `CONTINUOUS`, but is missing values: {}",
+                                cls.name(),
+                                shown.join(", ")
+                            ),
+                        );
+                    }
+                }
+                EnumCheck::NamedFlags => {
+                    if !enum_metadata.is_flag {
+                        continue;
+                    }
+                    let named_bits = int_values
+                        .iter()
+                        .filter(|(_, v)| *v > 0 && v.count_ones() == 1)
+                        .fold(0, |acc, (_, v)| acc | v);
+                    for (name, value) in &int_values {
+                        let unnamed = *value & !named_bits;
+                        if *value > 0 && value.count_ones() > 1 && unnamed != 0 {
+                            error(
+                                Some(name),
+                                format!(
+                                    "Flag alias `{name}` uses unnamed bits {unnamed:#x}, but `{}` is verified as `NAMED_FLAGS`",
+                                    cls.name()
+                                ),
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// The names listed in an enum's `_ignore_` attribute, given either as a list of strings or
+    /// as a single space-separated string. Must be called from the module defining `cls`.
+    pub fn enum_ignored_names(&self, cls: &Class) -> SmallSet<Name> {
+        let ignore = Name::new_static("_ignore_");
+        if !cls.contains(&ignore) {
+            return SmallSet::new();
+        }
+        let field = self.bindings().get(
+            self.bindings()
+                .key_to_idx(&KeyClassField(cls.index(), ignore)),
+        );
+        let ClassFieldInitialValue::Class(Some(value)) = &field.initial_value else {
+            return SmallSet::new();
+        };
+        match value {
+            Expr::StringLiteral(x) => x
+                .value
+                .to_str()
+                .replace(',', " ")
+                .split_whitespace()
+                .map(Name::new)
+                .collect(),
+            Expr::List(ExprList { elts, .. }) | Expr::Tuple(ExprTuple { elts, .. }) => elts
+                .iter()
+                .filter_map(|x| match x {
+                    Expr::StringLiteral(x) => Some(Name::new(x.value.to_str())),
+                    _ => None,
+                })
+                .collect(),
+            _ => SmallSet::new(),
+        }
+    }
+
+    /// Is `name` listed in the `_ignore_` attribute of the enum `cls`? Such names are removed from
+    /// the class when the enum is created, so they are not attributes at all.
+    pub fn is_ignored_enum_field(&self, cls: &Class, name: &Name) -> bool {
+        cls.contains(&Name::new_static("_ignore_"))
+            && self
+                .get_metadata_for_class(cls)
+                .enum_metadata()
+                .is_some_and(|enum_| enum_.ignored.contains(name))
+    }
+
     pub fn is_valid_enum_member(
         &self,
         name: &Name,
@@ -49,6 +263,8 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
         match ty {
             // Methods decorated with @member are members
             _ if ty.has_enum_member_decoration() => true,
+            // Values wrapped in member() are members, even if they are callable
+            Type::ClassType(cls) if cls.has_qname("enum", "member") => true,
             // Callables are not valid enum members
             Type::BoundMethod(_) | Type::Callable(_) | Type::Function(_) => false,
             // Values initialized with nonmember() are not members
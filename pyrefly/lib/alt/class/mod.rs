@@ -13,5 +13,6 @@ pub mod enums;
 pub mod named_tuple;
 pub mod new_type;
 pub mod targs;
+pub mod total_ordering;
 pub mod typed_dict;
 pub mod variance_inference;
@@ -41,21 +41,41 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
         elements.into_iter().map(|(name, _)| name).collect()
     }
 
-    pub fn named_tuple_element_types(&self, cls: &ClassType) -> Option<Vec<Type>> {
+    /// The fields of a NamedTuple in order, with their types specialized for `cls`, or `None` if
+    /// `cls` is not a NamedTuple.
+    pub fn named_tuple_field_types(&self, cls: &ClassType) -> Option<Vec<(Name, Type)>> {
         let class_metadata = self.get_metadata_for_class(cls.class_object());
         let named_tuple_metadata = class_metadata.named_tuple_metadata()?;
+        let substitution = cls.substitution();
         Some(
             named_tuple_metadata
                 .elements
                 .iter()
                 .filter_map(|name| {
-                    let attr = self.try_lookup_attr_from_class_type(cls.clone(), name)?;
-                    self.resolve_named_tuple_element(attr)
+                    let member = self.get_class_member(cls.class_object(), name)?;
+                    Some((
+                        name.clone(),
+                        substitution.substitute(member.value.as_named_tuple_type()),
+                    ))
                 })
                 .collect(),
         )
     }
 
+    /// The element types of a named tuple, specialized with the type arguments of `cls`.
+    ///
+    /// The element types declared on the class are expressed in terms of its type parameters, so
+    /// for a generic named tuple like `class Pair(NamedTuple, Generic[T])`, `Pair[int]` substitutes
+    /// `int` for `T` in each element.
+    pub fn named_tuple_element_types(&self, cls: &ClassType) -> Option<Vec<Type>> {
+        Some(
+            self.named_tuple_field_types(cls)?
+                .into_iter()
+                .map(|(_, ty)| ty)
+                .collect(),
+        )
+    }
+
     fn get_named_tuple_field_params(&self, cls: &Class, elements: &SmallSet<Name>) -> Vec<Param> {
         elements
             .iter()
@@ -36,7 +36,7 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
     /// caller to ensure they are not calling this method on a TypedDict class, which should be
     /// promoted to TypedDict instead of ClassType.
     pub fn promote_nontypeddict_silently_to_classtype(&self, cls: &Class) -> ClassType {
-        ClassType::new(cls.dupe(), self.create_default_targs(cls.tparams(), None))
+        ClassType::new(cls.dupe(), TArgs::for_unparametrized(cls))
     }
 
     /// Given a class or typed dictionary and some (explicit) type arguments, construct a `Type`
@@ -87,9 +87,13 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
     /// specialize(list, [int]) == list[int]
     /// promote(list) == list[Any]
     /// instantiate(list) == list[T]
-    pub fn promote(&self, cls: &Class, range: TextRange) -> Type {
-        let targs = self.create_default_targs(cls.tparams(), Some(range));
-        self.type_of_instance(cls, targs)
+    pub fn promote(
+        &self,
+        cls: &Class,
+        // Placeholder for strict mode, see `create_default_targs`.
+        _range: TextRange,
+    ) -> Type {
+        self.type_of_instance(cls, TArgs::for_unparametrized(cls))
     }
 
     pub fn promote_forall(&self, forall: Forall<Forallable>, range: TextRange) -> Type {
@@ -100,8 +104,7 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
     /// Version of `promote` that does not potentially raise errors.
     /// Should only be used for unusual scenarios.
     pub fn promote_silently(&self, cls: &Class) -> Type {
-        let targs = self.create_default_targs(cls.tparams(), None);
-        self.type_of_instance(cls, targs)
+        self.type_of_instance(cls, TArgs::for_unparametrized(cls))
     }
 
     /// Given a class or typed dictionary, create a `Type` that represents a generic instance of
@@ -122,7 +125,8 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
             .1
     }
 
-    /// Creates default type arguments for a class, falling back to Any for type parameters without defaults.
+    /// Creates default type arguments for a generic alias or function, falling back to Any for
+    /// type parameters without defaults. For classes, see `TArgs::for_unparametrized`.
     fn create_default_targs(
         &self,
         tparams: &TParams,
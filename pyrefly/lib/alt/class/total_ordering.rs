@@ -0,0 +1,61 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use crate::alt::answers::AnswersSolver;
+use crate::alt::answers::LookupAnswer;
+use crate::alt::types::class_metadata::ClassSynthesizedField;
+use crate::alt::types::class_metadata::ClassSynthesizedFields;
+use crate::types::callable::FuncMetadata;
+use crate::types::callable::Function;
+use crate::types::class::Class;
+use crate::types::types::Type;
+
+impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
+    /// The comparison methods added by `@functools.total_ordering`. Each one has the same
+    /// signature as the comparison method that the class defines.
+    pub fn get_total_ordering_synthesized_fields(
+        &self,
+        cls: &Class,
+    ) -> Option<ClassSynthesizedFields> {
+        let metadata = self.get_metadata_for_class(cls);
+        let total_ordering = metadata.total_ordering_metadata()?;
+        // Look the root up without going through this class's synthesized fields, which are what
+        // we are computing.
+        let root = if cls.contains(&total_ordering.root) {
+            self.get_field_from_current_class_only(cls, &total_ordering.root, false)
+        } else {
+            metadata.ancestors_no_object().iter().find_map(|ancestor| {
+                self.get_field_from_current_class_only(
+                    ancestor.class_object(),
+                    &total_ordering.root,
+                    false,
+                )
+            })
+        }?
+        .raw_type()
+        .clone();
+        let fields = total_ordering
+            .synthesized
+            .iter()
+            .map(|name| {
+                let ty = match &root {
+                    Type::Function(function) => Type::Function(Box::new(Function {
+                        signature: function.signature.clone(),
+                        metadata: FuncMetadata::def(
+                            self.module_info().name(),
+                            cls.name().clone(),
+                            name.clone(),
+                        ),
+                    })),
+                    ty => ty.clone(),
+                };
+                (name.clone(), ClassSynthesizedField::new(ty))
+            })
+            .collect();
+        Some(ClassSynthesizedFields::new(fields))
+    }
+}
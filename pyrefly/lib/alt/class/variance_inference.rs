@@ -16,6 +16,9 @@ use crate::alt::class::variance_inference::variance_visitor::TParamArray;
 use crate::alt::class::variance_inference::variance_visitor::VarianceEnv;
 use crate::alt::types::class_metadata::ClassMetadata;
 use crate::binding::binding::KeyExport;
+use crate::binding::binding::KeyVariance;
+use crate::error::collector::ErrorCollector;
+use crate::error::kind::ErrorKind;
 use crate::types::class::Class;
 use crate::types::type_var::PreInferenceVariance;
 use crate::types::type_var::Variance;
@@ -231,7 +234,8 @@ pub mod variance_visitor {
         // todo zeina: check if we need to check for things like __init_subclass__
         // in pyre 1, we didn't need to.
         for (name, field) in fields.iter() {
-            if name == "__init__" {
+            // Constructors are exempt from variance checks.
+            if name == "__init__" || name == "__new__" {
                 continue;
             }
 
@@ -359,6 +363,84 @@ fn loop_fn<'a>(
 }
 
 impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
+    /// Check that type parameters with an explicitly declared variance (e.g.
+    /// `TypeVar("T_co", covariant=True)`) are only used in positions compatible with that
+    /// variance. Type parameters whose variance is inferred don't need to be checked.
+    pub fn check_declared_variance(&self, class: &Class, errors: &ErrorCollector) {
+        let declared = class
+            .tparams()
+            .iter()
+            .filter_map(|param| match param.variance {
+                PreInferenceVariance::PCovariant => {
+                    Some((param.name().as_str(), Variance::Covariant))
+                }
+                PreInferenceVariance::PContravariant => {
+                    Some((param.name().as_str(), Variance::Contravariant))
+                }
+                PreInferenceVariance::PInvariant | PreInferenceVariance::PUndefined => None,
+            })
+            .collect::<SmallMap<_, _>>();
+        if declared.is_empty() {
+            return;
+        }
+
+        let mut used: SmallMap<String, Variance> = SmallMap::new();
+        let mut on_var = |name: &str, variance: Variance, _inj: Injectivity| {
+            if declared.contains_key(name) {
+                let entry = used.entry(name.to_owned()).or_insert(Variance::Bivariant);
+                *entry = entry.union(variance);
+            }
+        };
+        let mut on_edge = |c: &Class| {
+            // References to the class being checked use the declared variances, since those are
+            // the only ones we care about here (and looking them up would be a cycle).
+            let variances = if c == class {
+                None
+            } else {
+                Some(self.get_from_class(c, &KeyVariance(c.index())))
+            };
+            c.tparams()
+                .iter()
+                .map(|param| {
+                    let name = param.name().as_str();
+                    let variance = match &variances {
+                        Some(variances) => variances.0.get(name).copied(),
+                        None => declared.get(name).copied(),
+                    };
+                    (
+                        name.to_owned(),
+                        variance.unwrap_or(Variance::Bivariant),
+                        true,
+                    )
+                })
+                .collect()
+        };
+        variance_visitor::on_class(
+            class,
+            &mut on_edge,
+            &mut on_var,
+            &|c| self.get_metadata_for_class(c),
+            &|c| self.get_class_field_map(c),
+        );
+
+        for (name, declared_variance) in declared {
+            if let Some(used_variance) = used.get(name)
+                && used_variance.union(declared_variance) != declared_variance
+            {
+                self.error(
+                    errors,
+                    class.range(),
+                    ErrorKind::InvalidTypeVar,
+                    None,
+                    format!(
+                        "Type variable `{name}` is declared {declared_variance} but is used in {used_variance} position in class `{}`",
+                        class.name(),
+                    ),
+                );
+            }
+        }
+    }
+
     pub fn variance_map(&self, class: &Class) -> Arc<VarianceMap> {
         let mut contains_bivariant: bool = false;
 
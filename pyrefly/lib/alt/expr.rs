@@ -1396,6 +1396,13 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
                             self.call_issubclass(&x.arguments.args[0], &x.arguments.args[1], errors)
                         }
                         _ => {
+                            if let Type::ClassDef(cls) = &ty {
+                                self.check_abstract_class_instantiation(
+                                    cls,
+                                    x.arguments.range,
+                                    errors,
+                                );
+                            }
                             let callable = self.as_call_target_or_error(
                                 ty.clone(),
                                 CallStyle::FreeForm,
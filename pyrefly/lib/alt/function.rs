@@ -216,6 +216,30 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
             })
             .collect::<Vec<_>>();
 
+        // `__init_subclass__` is implicitly a classmethod, so it may be left undecorated or
+        // decorated with `@classmethod`, but any other kind of method is a mistake.
+        if def.name.id == dunder::INIT_SUBCLASS && defining_cls.is_some() {
+            let bad_decorator = if is_staticmethod {
+                Some("staticmethod")
+            } else if is_property_getter {
+                Some("property")
+            } else {
+                None
+            };
+            if let Some(bad_decorator) = bad_decorator {
+                self.error(
+                    errors,
+                    def.name.range,
+                    ErrorKind::BadFunctionDefinition,
+                    None,
+                    format!(
+                        "`{}` is implicitly a classmethod and cannot be decorated with `@{bad_decorator}`",
+                        dunder::INIT_SUBCLASS,
+                    ),
+                );
+            }
+        }
+
         // Look for a @classmethod or @staticmethod decorator and change the "self" type
         // accordingly. This is not totally correct, since it doesn't account for chaining
         // decorators, or weird cases like both decorators existing at the same time.
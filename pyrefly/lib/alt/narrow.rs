@@ -19,6 +19,7 @@ use ruff_python_ast::StringLiteralValue;
 use ruff_python_ast::name::Name;
 use ruff_text_size::Ranged;
 use ruff_text_size::TextRange;
+use vec1::Vec1;
 
 use crate::alt::answers::AnswersSolver;
 use crate::alt::answers::LookupAnswer;
@@ -338,6 +339,34 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
                     result
                 })
             }
+            AtomicNarrowOp::HasKey(key) => {
+                let key = Name::new(key);
+                self.distribute_over_union(ty, |t| match t {
+                    // A TypedDict that doesn't declare the key may still have it at runtime
+                    // (it could be a structural subtype), unless it is final.
+                    Type::TypedDict(td)
+                        if !self.typed_dict_fields(td).contains_key(&key)
+                            && self.get_metadata_for_class(td.class_object()).is_final() =>
+                    {
+                        Type::never()
+                    }
+                    _ => t.clone(),
+                })
+            }
+            AtomicNarrowOp::NotHasKey(key) => {
+                let key = Name::new(key);
+                self.distribute_over_union(ty, |t| match t {
+                    Type::TypedDict(td)
+                        if self
+                            .typed_dict_fields(td)
+                            .get(&key)
+                            .is_some_and(|field| field.required) =>
+                    {
+                        Type::never()
+                    }
+                    _ => t.clone(),
+                })
+            }
             AtomicNarrowOp::Is(v) => {
                 let right = self.expr_infer(v, errors);
                 // Get our best approximation of ty & right.
@@ -470,6 +499,35 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
         }
     }
 
+    /// After `"key" in x`, the key is known to be present in `x`, so we narrow the `x["key"]` facet.
+    /// Reads of `NotRequired` keys through a narrowed facet are treated as safe. We only do this
+    /// if every member of the narrowed type is a TypedDict that declares the key.
+    fn present_key_narrow(
+        &self,
+        ty: &Type,
+        op: &AtomicNarrowOp,
+        facets: Vec<FacetKind>,
+    ) -> Option<(Vec1<FacetKind>, Type)> {
+        let AtomicNarrowOp::HasKey(key) = op else {
+            return None;
+        };
+        let name = Name::new(key);
+        let mut key_tys = Vec::new();
+        for t in ty.clone().into_unions() {
+            match t {
+                Type::TypedDict(td) => key_tys.push(self.typed_dict_field(&td, &name)?.ty),
+                _ => return None,
+            }
+        }
+        if key_tys.is_empty() {
+            return None;
+        }
+        Some((
+            Vec1::from_vec_push(facets, FacetKind::Key(key.clone())),
+            self.unions(key_tys),
+        ))
+    }
+
     fn get_facet_chain_type(
         &self,
         base: &TypeInfo,
@@ -598,9 +656,13 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
     ) -> TypeInfo {
         match op {
             NarrowOp::Atomic(None, op) => {
-                type_info
-                    .clone()
-                    .with_ty(self.atomic_narrow(type_info.ty(), op, range, errors))
+                let ty = self.atomic_narrow(type_info.ty(), op, range, errors);
+                let key_narrow = self.present_key_narrow(&ty, op, Vec::new());
+                let type_info = type_info.clone().with_ty(ty);
+                match key_narrow {
+                    Some((facets, key_ty)) => type_info.with_narrow(&facets, key_ty),
+                    None => type_info,
+                }
             }
             NarrowOp::Atomic(Some(facet_chain), op) => {
                 let ty = self.atomic_narrow(
@@ -609,7 +671,12 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
                     range,
                     errors,
                 );
-                type_info.with_narrow(facet_chain.facets(), ty)
+                let key_narrow = self.present_key_narrow(&ty, op, facet_chain.facets().to_vec());
+                let type_info = type_info.with_narrow(facet_chain.facets(), ty);
+                match key_narrow {
+                    Some((facets, key_ty)) => type_info.with_narrow(&facets, key_ty),
+                    None => type_info,
+                }
             }
             NarrowOp::And(ops) => {
                 let mut ops_iter = ops.iter();
@@ -1350,22 +1350,33 @@ pub fn solve_binding(&self, binding: &Binding, errors: &ErrorCollector) -> Arc<T
     ) -> Arc<ClassSynthesizedFields> {
         let fields = match &self.get_idx(fields.0).0 {
             None => ClassSynthesizedFields::default(),
-            Some(cls) => self
-                .get_typed_dict_synthesized_fields(cls)
-                .or_else(|| self.get_dataclass_synthesized_fields(cls))
-                .or_else(|| self.get_named_tuple_synthesized_fields(cls))
-                .or_else(|| self.get_new_type_synthesized_fields(cls))
-                .unwrap_or_default(),
+            Some(cls) => {
+                let fields = self
+                    .get_typed_dict_synthesized_fields(cls)
+                    .or_else(|| self.get_dataclass_synthesized_fields(cls))
+                    .or_else(|| self.get_named_tuple_synthesized_fields(cls))
+                    .or_else(|| self.get_new_type_synthesized_fields(cls))
+                    .unwrap_or_default();
+                match self.get_total_ordering_synthesized_fields(cls) {
+                    Some(total_ordering) => fields.combine(total_ordering),
+                    None => fields,
+                }
+            }
         };
         Arc::new(fields)
     }
     // TODO zeina: After doing the full implementation, look into extracting fields and
     // base types from existing bindings
-    pub fn solve_variance_binding(&self, variance_info: &BindingVariance) -> Arc<VarianceMap> {
+    pub fn solve_variance_binding(
+        &self,
+        variance_info: &BindingVariance,
+        errors: &ErrorCollector,
+    ) -> Arc<VarianceMap> {
         let class_idx = variance_info.class_key;
         let class = self.get_idx(class_idx);
 
         if let Some(class) = &class.0 {
+            self.check_declared_variance(class, errors);
             self.variance_map(class)
         } else {
             Arc::new(VarianceMap(SmallMap::new()))
@@ -2539,6 +2550,7 @@ pub fn solve_binding(&self, binding: &Binding, errors: &ErrorCollector) -> Arc<T
             Binding::ClassDef(x, decorators) => match &self.get_idx(*x).0 {
                 None => Type::any_implicit(),
                 Some(cls) => {
+                    self.check_enum_verify(cls, errors);
                     let mut ty = Type::ClassDef(cls.dupe());
                     for x in decorators.iter().rev() {
                         ty = self.apply_decorator(*x, ty, errors)
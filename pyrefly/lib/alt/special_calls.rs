@@ -384,6 +384,11 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
         // Use protocol metadata to get the member names
         let metadata = self.get_metadata_for_class(cls);
         if let Some(protocol_metadata) = metadata.protocol_metadata() {
+            // Annotated attributes are data members even if their type is callable, since they
+            // aren't set on the class.
+            if protocol_metadata.has_data_members {
+                return true;
+            }
             for field_name in &protocol_metadata.members {
                 // Use the class type to access the field
                 let class_type = cls.as_class_type();
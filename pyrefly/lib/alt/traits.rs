@@ -278,9 +278,9 @@ impl<Ans: LookupAnswer> Solve<Ans> for KeyVariance {
     fn solve(
         answers: &AnswersSolver<Ans>,
         binding: &BindingVariance,
-        _errors: &ErrorCollector,
+        errors: &ErrorCollector,
     ) -> Arc<VarianceMap> {
-        answers.solve_variance_binding(binding)
+        answers.solve_variance_binding(binding, errors)
     }
 
     fn create_recursive(_: &AnswersSolver<Ans>, _: &Self::Value) -> Self::Recursive {}
@@ -16,6 +16,7 @@ use pyrefly_derive::VisitMut;
 use pyrefly_util::display::commas_iter;
 use pyrefly_util::visit::VisitMut;
 use ruff_python_ast::name::Name;
+use ruff_text_size::TextRange;
 use starlark_map::small_map::SmallMap;
 use starlark_map::small_set::SmallSet;
 use vec1::Vec1;
@@ -42,7 +43,16 @@ pub struct ClassMetadata {
     enum_metadata: Option<EnumMetadata>,
     protocol_metadata: Option<ProtocolMetadata>,
     dataclass_metadata: Option<DataclassMetadata>,
+    total_ordering_metadata: Option<TotalOrderingMetadata>,
     bases_with_metadata: Vec<(ClassType, Arc<ClassMetadata>)>,
+    /// The instance attributes allowed by `__slots__`, or `None` if instances have a `__dict__`
+    /// and so may be given any attribute.
+    slots: Option<SmallSet<Name>>,
+    /// Methods decorated with `@abstractmethod` that this class neither defines nor inherits an
+    /// implementation of.
+    abstract_methods: SmallSet<Name>,
+    /// Does this class have an `Any` (or otherwise unknown) class among its ancestors? If so,
+    /// attribute lookups that fail to find a member fall back to `Any`.
     has_base_any: bool,
     is_new_type: bool,
     is_final: bool,
@@ -69,6 +79,7 @@ impl ClassMetadata {
     pub fn new(
         cls: &Class,
         bases_with_metadata: Vec<(ClassType, Arc<ClassMetadata>)>,
+        base_ranges: &[TextRange],
         metaclass: Option<ClassType>,
         keywords: Vec<(Name, Type)>,
         typed_dict_metadata: Option<TypedDictMetadata>,
@@ -76,17 +87,24 @@ impl ClassMetadata {
         enum_metadata: Option<EnumMetadata>,
         protocol_metadata: Option<ProtocolMetadata>,
         dataclass_metadata: Option<DataclassMetadata>,
+        total_ordering_metadata: Option<TotalOrderingMetadata>,
+        slots: Option<SmallSet<Name>>,
         has_base_any: bool,
         is_new_type: bool,
         is_final: bool,
         has_unknown_tparams: bool,
         errors: &ErrorCollector,
     ) -> ClassMetadata {
-        let mro = Mro::new(cls, &bases_with_metadata, errors);
+        let mro = Mro::new(cls, &bases_with_metadata, base_ranges, errors);
+        // We don't know the real ancestors of a class with cyclic bases, so we treat it as
+        // extending `Any` to avoid cascading errors.
+        let has_base_any = has_base_any || matches!(mro, Mro::Cyclic);
+        let abstract_methods = Self::compute_abstract_methods(cls, &mro, &bases_with_metadata);
         Self::validate_frozen_dataclass_inheritance(
             cls,
             &dataclass_metadata,
             &bases_with_metadata,
+            base_ranges,
             errors,
         );
         ClassMetadata {
@@ -98,7 +116,10 @@ impl ClassMetadata {
             enum_metadata,
             protocol_metadata,
             dataclass_metadata,
+            total_ordering_metadata,
             bases_with_metadata,
+            slots,
+            abstract_methods,
             has_base_any,
             is_new_type,
             is_final,
@@ -106,14 +127,43 @@ impl ClassMetadata {
         }
     }
 
+    /// An abstract method stays abstract unless something earlier in the MRO defines the same name.
+    fn compute_abstract_methods(
+        cls: &Class,
+        mro: &Mro,
+        bases_with_metadata: &[(ClassType, Arc<ClassMetadata>)],
+    ) -> SmallSet<Name> {
+        let candidates = cls
+            .fields()
+            .filter(|name| cls.is_field_abstract_method(name))
+            .chain(
+                bases_with_metadata
+                    .iter()
+                    .flat_map(|(_, metadata)| metadata.abstract_methods.iter()),
+            );
+        let mut abstract_methods = SmallSet::new();
+        for name in candidates {
+            let defining_class = iter::once(cls)
+                .chain(mro.ancestors_no_object().iter().map(|c| c.class_object()))
+                .find(|c| c.contains(name));
+            if defining_class.is_some_and(|c| c.is_field_abstract_method(name)) {
+                abstract_methods.insert(name.clone());
+            }
+        }
+        abstract_methods
+    }
+
     fn validate_frozen_dataclass_inheritance(
         cls: &Class,
         dataclass_metadata: &Option<DataclassMetadata>,
         bases_with_metadata: &[(ClassType, Arc<ClassMetadata>)],
+        base_ranges: &[TextRange],
         errors: &ErrorCollector,
     ) {
         if let Some(dataclass_metadata) = dataclass_metadata {
-            for (base_type, base_metadata) in bases_with_metadata {
+            for ((base_type, base_metadata), base_range) in
+                bases_with_metadata.iter().zip(base_ranges)
+            {
                 if let Some(base_dataclass_metadata) = base_metadata.dataclass_metadata() {
                     let is_base_frozen = base_dataclass_metadata
                         .kws
@@ -134,7 +184,7 @@ impl ClassMetadata {
                         };
 
                         errors.add(
-                            cls.range(),
+                            *base_range,
                             ErrorKind::InvalidInheritance,
                             None,
                             vec1![format!(
@@ -161,8 +211,11 @@ impl ClassMetadata {
             enum_metadata: None,
             protocol_metadata: None,
             dataclass_metadata: None,
+            total_ordering_metadata: None,
             bases_with_metadata: Vec::new(),
-            has_base_any: false,
+            slots: None,
+            abstract_methods: SmallSet::new(),
+            has_base_any: true,
             is_new_type: false,
             is_final: false,
             has_unknown_tparams: false,
@@ -236,6 +289,46 @@ impl ClassMetadata {
         self.dataclass_metadata.as_ref()
     }
 
+    /// Render `cls` along with its direct base classes, e.g. `class C[T](A[int], B[T]): ...`.
+    /// Unlike the `Display` for `Class`, this needs the computed metadata, since that is where the
+    /// bases live. A lone `object` base is left out, since it is implied.
+    pub fn display_class_with_bases(&self, cls: &Class) -> String {
+        let mut res = format!("class {}", cls.name());
+        if !cls.tparams().is_empty() {
+            res.push_str(&format!("[{}]", commas_iter(|| cls.tparams().iter())));
+        }
+        let bases = match &*self.bases_with_metadata {
+            [(base, _)] if base.has_qname("builtins", "object") => &[],
+            bases => bases,
+        };
+        if !bases.is_empty() {
+            res.push_str(&format!(
+                "({})",
+                commas_iter(|| bases.iter().map(|(base, _)| base))
+            ));
+        }
+        res.push_str(": ...");
+        res
+    }
+
+    pub fn slots(&self) -> Option<&SmallSet<Name>> {
+        self.slots.as_ref()
+    }
+
+    /// Does this class have abstract methods without an implementation? Such a class cannot be
+    /// instantiated.
+    pub fn is_abstract(&self) -> bool {
+        !self.abstract_methods.is_empty()
+    }
+
+    pub fn abstract_methods(&self) -> &SmallSet<Name> {
+        &self.abstract_methods
+    }
+
+    pub fn total_ordering_metadata(&self) -> Option<&TotalOrderingMetadata> {
+        self.total_ordering_metadata.as_ref()
+    }
+
     pub fn ancestors<'a>(&'a self, stdlib: &'a Stdlib) -> impl Iterator<Item = &'a ClassType> {
         self.ancestors_no_object()
             .iter()
@@ -296,6 +389,14 @@ impl ClassSynthesizedFields {
     pub fn get(&self, name: &Name) -> Option<&ClassSynthesizedField> {
         self.0.get(name)
     }
+
+    /// Combine two sets of synthesized fields. If both define a field, the one in `self` wins.
+    pub fn combine(mut self, other: Self) -> Self {
+        for (name, field) in other.0 {
+            self.0.entry(name).or_insert(field);
+        }
+        self
+    }
 }
 
 impl Display for ClassSynthesizedFields {
@@ -356,6 +457,23 @@ pub struct EnumMetadata {
     pub is_flag: bool,
     /// Is there any `_value_` field present.
     pub has_value: bool,
+    /// The concrete data type mixed into this enum, e.g. `int` for an `IntEnum`.
+    pub mixin: Option<ClassType>,
+    /// The checks requested by an `@enum.verify(...)` decorator.
+    pub checks: SmallSet<EnumCheck>,
+    /// The names listed in `_ignore_`, which are removed from the class at runtime.
+    pub ignored: SmallSet<Name>,
+}
+
+/// A constraint on enum members that can be requested with `@enum.verify(...)`.
+#[derive(Clone, Copy, Debug, TypeEq, PartialEq, Eq, Hash)]
+pub enum EnumCheck {
+    /// `UNIQUE`: no two members have the same value.
+    Unique,
+    /// `CONTINUOUS`: there are no gaps in the integer values.
+    Continuous,
+    /// `NAMED_FLAGS`: every bit used by a flag alias is a named member.
+    NamedFlags,
 }
 
 #[derive(Clone, Debug, TypeEq, PartialEq, Eq)]
@@ -363,6 +481,15 @@ pub struct NamedTupleMetadata {
     pub elements: SmallSet<Name>,
 }
 
+/// Metadata for a class decorated with `@functools.total_ordering`.
+#[derive(Clone, Debug, TypeEq, PartialEq, Eq)]
+pub struct TotalOrderingMetadata {
+    /// The comparison method that the missing ones are derived from.
+    pub root: Name,
+    /// The comparison methods that `total_ordering` adds to the class.
+    pub synthesized: Vec<Name>,
+}
+
 #[derive(Clone, Debug, TypeEq, PartialEq, Eq)]
 pub struct DataclassMetadata {
     /// The dataclass fields, e.g., `{'x'}` for `@dataclass class C: x: int`.
@@ -389,6 +516,12 @@ pub struct ProtocolMetadata {
     pub members: SmallSet<Name>,
     /// Whether this protocol is decorated with @runtime_checkable
     pub is_runtime_checkable: bool,
+    /// Whether any member is an annotated attribute rather than a method or property. Such
+    /// "data protocols" cannot be used with `issubclass()`.
+    pub has_data_members: bool,
+    /// Members annotated with `ClassVar`, which must be provided by the class itself rather than
+    /// by its instances.
+    pub class_vars: SmallSet<Name>,
 }
 
 /// A struct representing a class's ancestors, in method resolution order (MRO)
@@ -447,12 +580,15 @@ impl Mro {
     ///
     /// TODO: We currently omit some classes that are in the runtime MRO:
     /// `Generic`, `Protocol`, and `object`.
+    /// Compute the MRO of `cls`. `base_ranges` gives the location of each base in the class
+    /// definition, and is used to report errors that concern a specific base.
     pub fn new(
         cls: &Class,
         bases_with_metadata: &[(ClassType, Arc<ClassMetadata>)],
+        base_ranges: &[TextRange],
         errors: &ErrorCollector,
     ) -> Self {
-        match Linearization::new(cls, bases_with_metadata, errors) {
+        match Linearization::new(cls, bases_with_metadata, base_ranges, errors) {
             Linearization::Cyclic => Self::Cyclic,
             Linearization::Resolved(ancestor_chains) => {
                 let ancestors = Linearization::merge(cls, ancestor_chains, errors);
@@ -508,6 +644,7 @@ impl Linearization {
     fn new(
         cls: &Class,
         bases_with_metadata: &[(ClassType, Arc<ClassMetadata>)],
+        base_ranges: &[TextRange],
         errors: &ErrorCollector,
     ) -> Linearization {
         let bases = match Vec1::try_from_vec(
@@ -521,7 +658,7 @@ impl Linearization {
             Err(_) => return Linearization::empty(),
         };
         let mut ancestor_chains = Vec::new();
-        for (base, mro) in bases_with_metadata {
+        for ((base, mro), base_range) in bases_with_metadata.iter().zip(base_ranges) {
             match &**mro {
                 ClassMetadata {
                     mro: Mro::Resolved(ancestors),
@@ -540,18 +677,25 @@ impl Linearization {
                 // None and Cyclic both indicate a cycle, the distinction just
                 // depends on how exactly the recursion in resolving keys plays out.
                 ClassMetadata {
-                    mro: Mro::Cyclic, ..
+                    mro: Mro::Cyclic,
+                    bases_with_metadata: base_bases,
+                    ..
                 } => {
-                    errors.add(
-                        cls.range(),
-                        ErrorKind::InvalidInheritance,
-                        None,
-                        vec1![format!(
-                            "Class `{}` inheriting from `{}` creates a cycle",
-                            ClassName(cls.qname()),
-                            ClassName(base.qname()),
-                        )],
-                    );
+                    // Every class in the cycle ends up with a cyclic MRO, but we only report
+                    // the cycle once: at the edge where the recursion was detected, where the
+                    // base's metadata is the placeholder from `ClassMetadata::recursive()`.
+                    if base_bases.is_empty() {
+                        errors.add(
+                            *base_range,
+                            ErrorKind::InvalidInheritance,
+                            None,
+                            vec1![format!(
+                                "Class `{}` inheriting from `{}` creates a cycle",
+                                ClassName(cls.qname()),
+                                ClassName(base.qname()),
+                            )],
+                        );
+                    }
                     // Signal that we detected a cycle
                     return Linearization::Cyclic;
                 }
@@ -601,9 +745,15 @@ impl Linearization {
             if let Some(selected) = selected {
                 // Strip the selected class from all chains. Any empty chain is removed.
                 let mut chains_to_remove = Vec::new();
+                let mut conflict = None;
                 for (idx, ancestors) in ancestor_chains.iter_mut().enumerate() {
                     if ancestors.0.last().class_object().qname() == selected.class_object().qname()
                     {
+                        // The same generic ancestor may be reached through more than one base, in
+                        // which case every path has to agree on its type arguments.
+                        if conflict.is_none() && !selected.gradually_equal(ancestors.0.last()) {
+                            conflict = Some(ancestors.0.last().clone());
+                        }
                         match ancestors.0.pop() {
                             Ok(_) => {}
                             Err(_) => chains_to_remove.push(idx),
@@ -613,6 +763,20 @@ impl Linearization {
                 for (offset, idx) in chains_to_remove.into_iter().enumerate() {
                     ancestor_chains.remove(idx - offset);
                 }
+                if let Some(conflict) = conflict {
+                    errors.add(
+                        cls.range(),
+                        ErrorKind::InvalidInheritance,
+                        None,
+                        vec1![format!(
+                            "Class `{}` inherits from `{}` with conflicting type arguments `{}` and `{}`",
+                            ClassName(cls.qname()),
+                            ClassName(selected.qname()),
+                            selected.display_targs_or_empty(),
+                            conflict.display_targs_or_empty(),
+                        )],
+                    );
+                }
                 // Push the selected class onto the result
                 ancestors.push(selected);
             } else {
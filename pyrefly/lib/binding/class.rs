@@ -205,6 +205,14 @@ impl<'a> BindingsBuilder<'a> {
         self.scopes.pop(); // annotation scope
         let mut fields_possibly_defined_by_this_class =
             SmallMap::with_capacity(last_scope.stat.0.len());
+        let is_class_var = |name: &Name| match &last_scope.kind {
+            ScopeKind::Class(class_scope) => class_scope.is_class_var(name),
+            _ => false,
+        };
+        let is_abstract_method = |name: &Name| match &last_scope.kind {
+            ScopeKind::Class(class_scope) => class_scope.is_abstract_method(name),
+            _ => false,
+        };
         for (name, info) in last_scope.flow.info.iter_hashed() {
             let is_function_without_return_annotation =
                 if let FlowStyle::FunctionDef(_, has_return_annotation) = info.style {
@@ -232,7 +240,12 @@ impl<'a> BindingsBuilder<'a> {
                 };
                 fields_possibly_defined_by_this_class.insert_hashed(
                     name.cloned(),
-                    ClassFieldProperties::new(stat_info.annot.is_some(), stat_info.loc),
+                    ClassFieldProperties::new(
+                        stat_info.annot.is_some(),
+                        is_class_var(name.key()),
+                        is_abstract_method(name.key()),
+                        stat_info.loc,
+                    ),
                 );
 
                 let key_field = KeyClassField(class_indices.def_index, name.into_key().clone());
@@ -260,7 +273,7 @@ impl<'a> BindingsBuilder<'a> {
                     };
                     fields_possibly_defined_by_this_class.insert_hashed(
                         name.clone(),
-                        ClassFieldProperties::new(annotation.is_some(), range),
+                        ClassFieldProperties::new(annotation.is_some(), false, false, range),
                     );
 
                     let key_field = KeyClassField(class_indices.def_index, name.key().clone());
@@ -470,6 +483,8 @@ impl<'a> BindingsBuilder<'a> {
                 member_name.clone(),
                 ClassFieldProperties::new(
                     member_annotation.is_some() || class_kind == SynthesizedClassKind::NamedTuple,
+                    false,
+                    false,
                     range,
                 ),
             );
@@ -540,6 +555,19 @@ impl<'a> BindingsBuilder<'a> {
         );
     }
 
+    /// Whether the members of a functional enum definition are spelled out literally, in one of
+    /// the forms that `synthesize_enum_def` knows how to turn into a class.
+    fn has_literal_enum_members(members: &[Expr]) -> bool {
+        match members {
+            [Expr::StringLiteral(_), ..] | [Expr::Dict(_)] => true,
+            [Expr::List(ExprList { elts, .. }) | Expr::Tuple(ExprTuple { elts, .. })] => matches!(
+                elts.as_slice(),
+                [Expr::StringLiteral(_) | Expr::Tuple(_), ..]
+            ),
+            _ => false,
+        }
+    }
+
     pub fn synthesize_enum_def(
         &mut self,
         name: &ExprName,
@@ -548,6 +576,23 @@ impl<'a> BindingsBuilder<'a> {
         members: &mut [Expr],
     ) {
         let class_name = Ast::expr_name_identifier(name.clone());
+        if !Self::has_literal_enum_members(members) {
+            // We can't know the members statically, so we treat the enum as `Any`.
+            let mut user = self.declare_user(Key::Definition(ShortIdentifier::new(&class_name)));
+            self.check_functional_definition_name(&name.id, arg_name);
+            self.ensure_expr(func, user.usage());
+            self.ensure_expr(arg_name, user.usage());
+            for arg in &mut *members {
+                self.ensure_expr(arg, user.usage());
+            }
+            self.bind_definition_user(
+                &class_name,
+                user,
+                Binding::Type(Type::type_form(Type::any_implicit())),
+                FlowStyle::Other,
+            );
+            return;
+        }
         let (mut class_object, class_indices) = self.class_object_and_indices(&class_name);
         self.check_functional_definition_name(&name.id, arg_name);
         self.ensure_expr(func, class_object.usage());
@@ -57,6 +57,7 @@ use crate::types::types::Type;
 
 struct Decorators {
     has_no_type_check: bool,
+    is_abstract_method: bool,
     decorators: Box<[Idx<Key>]>,
 }
 
@@ -401,12 +402,16 @@ impl<'a> BindingsBuilder<'a> {
         let has_no_type_check = decorator_list
             .iter()
             .any(|d| self.as_special_export(&d.expression) == Some(SpecialExport::NoTypeCheck));
+        let is_abstract_method = decorator_list
+            .iter()
+            .any(|d| self.as_special_export(&d.expression) == Some(SpecialExport::AbstractMethod));
 
         let decorators = self
             .ensure_and_bind_decorators(decorator_list, usage)
             .into_boxed_slice();
         Decorators {
             has_no_type_check,
+            is_abstract_method,
             decorators,
         }
     }
@@ -527,6 +532,8 @@ impl<'a> BindingsBuilder<'a> {
         self.scopes.pop();
         self.scopes
             .record_self_assignments_if_applicable(self_assignments);
+        self.scopes
+            .record_abstract_method(func_name.id.clone(), decorators.is_abstract_method);
 
         self.insert_binding_idx(
             function_idx,
@@ -55,6 +55,9 @@ pub enum AtomicNarrowOp {
     NotTypeIs(Type, Arguments),
     In(Expr),
     NotIn(Expr),
+    /// Used to narrow TypedDicts based on whether a key is present, e.g. `"key" in x`
+    HasKey(String),
+    NotHasKey(String),
     /// Used to narrow tuple types based on length
     LenEq(Expr),
     LenNotEq(Expr),
@@ -144,6 +147,8 @@ impl AtomicNarrowOp {
             Self::NotEq(v) => Self::Eq(v.clone()),
             Self::In(v) => Self::NotIn(v.clone()),
             Self::NotIn(v) => Self::In(v.clone()),
+            Self::HasKey(key) => Self::NotHasKey(key.clone()),
+            Self::NotHasKey(key) => Self::HasKey(key.clone()),
             Self::LenEq(v) => Self::LenNotEq(v.clone()),
             Self::LenNotEq(v) => Self::LenEq(v.clone()),
             Self::TypeGuard(ty, args) => Self::NotTypeGuard(ty.clone(), args.clone()),
@@ -294,6 +299,19 @@ impl NarrowOps {
                 ops: cmp_ops,
                 comparators,
             })) => {
+                // `"key" in x` narrows `x`, rather than the left expression
+                if let Expr::StringLiteral(ExprStringLiteral { value: key, .. }) = &**left
+                    && let [cmp_op @ (CmpOp::In | CmpOp::NotIn)] = &**cmp_ops
+                    && let [right] = &**comparators
+                {
+                    let key = key.to_string();
+                    let op = if matches!(cmp_op, CmpOp::In) {
+                        AtomicNarrowOp::HasKey(key)
+                    } else {
+                        AtomicNarrowOp::NotHasKey(key)
+                    };
+                    return Self::from_single_narrow_op(right, op, right.range());
+                }
                 // If the left expression is a call to len(), we're narrowing the argument
                 let mut left = &**left;
                 let mut lhs_is_len = false;
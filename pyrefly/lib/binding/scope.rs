@@ -299,6 +299,10 @@ pub struct ScopeClass {
     pub indices: ClassIndices,
     attributes_from_recognized_methods: SmallMap<Name, SmallMap<Name, InstanceAttribute>>,
     attributes_from_other_methods: SmallMap<Name, SmallMap<Name, InstanceAttribute>>,
+    /// Names whose annotation in the class body is qualified with `ClassVar`.
+    class_vars: SmallSet<Name>,
+    /// Methods whose last definition in the class body is decorated with `@abstractmethod`.
+    abstract_methods: SmallSet<Name>,
 }
 
 /// The method where an attribute was defined implicitly by assignment to `self.<attr_name>`
@@ -318,9 +322,19 @@ impl ScopeClass {
             indices,
             attributes_from_recognized_methods: SmallMap::new(),
             attributes_from_other_methods: SmallMap::new(),
+            class_vars: SmallSet::new(),
+            abstract_methods: SmallSet::new(),
         }
     }
 
+    pub fn is_class_var(&self, name: &Name) -> bool {
+        self.class_vars.contains(name)
+    }
+
+    pub fn is_abstract_method(&self, name: &Name) -> bool {
+        self.abstract_methods.contains(name)
+    }
+
     pub fn add_attributes_defined_by_method(
         &mut self,
         method_name: Name,
@@ -906,6 +920,25 @@ impl Scopes {
         }
     }
 
+    /// Record that a name in the current class body is annotated with `ClassVar`.
+    pub fn record_class_var(&mut self, name: Name) {
+        if let ScopeKind::Class(class_scope) = &mut self.current_mut().kind {
+            class_scope.class_vars.insert(name);
+        }
+    }
+
+    /// Record whether a method defined in the current class body is decorated with
+    /// `@abstractmethod`. A later definition of the same name replaces an earlier one.
+    pub fn record_abstract_method(&mut self, name: Name, is_abstract: bool) {
+        if let ScopeKind::Class(class_scope) = &mut self.current_mut().kind {
+            if is_abstract {
+                class_scope.abstract_methods.insert(name);
+            } else {
+                class_scope.abstract_methods.shift_remove(&name);
+            }
+        }
+    }
+
     fn current_yields_and_returns_mut(&mut self) -> Option<&mut YieldsAndReturns> {
         for scope in self.iter_rev_mut() {
             match &mut scope.kind {
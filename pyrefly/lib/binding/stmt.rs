@@ -194,6 +194,16 @@ impl<'a> BindingsBuilder<'a> {
         self.insert_binding(key, binding);
     }
 
+    /// Whether an annotation is `ClassVar` or `ClassVar[...]`. Class metadata needs to know about
+    /// `ClassVar` fields before any types are solved, so this is a syntactic check.
+    fn is_class_var_annotation(&self, annotation: &Expr) -> bool {
+        let annotation = match annotation {
+            Expr::Subscript(x) => &*x.value,
+            _ => annotation,
+        };
+        self.as_special_export(annotation) == Some(SpecialExport::ClassVar)
+    }
+
     /// Bind the annotation in an `AnnAssign`
     pub fn bind_annotation(
         &mut self,
@@ -203,6 +213,9 @@ impl<'a> BindingsBuilder<'a> {
     ) -> Idx<KeyAnnotation> {
         let ann_key = KeyAnnotation::Annotation(ShortIdentifier::new(name));
         self.ensure_type(annotation, &mut None);
+        if self.scopes.in_class_body() && self.is_class_var_annotation(annotation) {
+            self.scopes.record_class_var(name.id.clone());
+        }
         let ann_val = if let Some(special) = SpecialForm::new(&name.id, annotation) {
             // Special case `_: SpecialForm` declarations (this mainly affects some names declared in `typing.pyi`)
             BindingAnnotation::Type(
@@ -313,7 +326,8 @@ impl<'a> BindingsBuilder<'a> {
                             }
                             SpecialExport::Enum
                             | SpecialExport::IntEnum
-                            | SpecialExport::StrEnum => {
+                            | SpecialExport::StrEnum
+                            | SpecialExport::Flag => {
                                 if let Some((arg_name, members)) =
                                     call.arguments.args.split_first_mut()
                                 {
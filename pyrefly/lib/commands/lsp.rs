@@ -1621,6 +1621,8 @@ impl Server {
         let info = transaction.get_module_info(&handle)?;
         let range = position_to_text_size(&info, params.text_document_position_params.position);
         let t = transaction.get_type_at(&handle, range)?;
+        // For a class, show its header with its base classes rather than just `type[C]`.
+        let class_header = transaction.display_class_with_bases(&handle, &t);
         let mut kind_formatted: String = "".to_owned();
         let mut docstring_formatted: String = "".to_owned();
         if let Some((definition_metadata, text_range_with_module_info, docstring)) =
@@ -1643,8 +1645,9 @@ impl Server {
             contents: HoverContents::Markup(MarkupContent {
                 kind: MarkupKind::Markdown,
                 value: format!(
-                    "```python\n{}{}\n```{}",
-                    kind_formatted, t, docstring_formatted
+                    "```python\n{}\n```{}",
+                    class_header.unwrap_or_else(|| format!("{kind_formatted}{t}")),
+                    docstring_formatted
                 ),
             }),
             range: None,
@@ -45,6 +45,7 @@ pub const POS: Name = Name::new_static("__pos__");
 pub const SET: Name = Name::new_static("__set__");
 pub const SETATTR: Name = Name::new_static("__setattr__");
 pub const SETITEM: Name = Name::new_static("__setitem__");
+pub const SLOTS: Name = Name::new_static("__slots__");
 pub const BOOL: Name = Name::new_static("__bool__");
 
 pub const RICH_CMPS: &[Name] = &[LT, LE, EQ, NE, GT, GE];
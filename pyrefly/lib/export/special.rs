@@ -16,6 +16,7 @@ use crate::module::module_name::ModuleName;
 #[derive(Debug, Clone, Dupe, Copy, PartialEq, Eq)]
 pub enum SpecialExport {
     TypeAlias,
+    ClassVar,
     TypeVar,
     ParamSpec,
     TypeVarTuple,
@@ -24,6 +25,7 @@ pub enum SpecialExport {
     Enum,
     StrEnum,
     IntEnum,
+    Flag,
     TypedDict,
     CollectionsNamedTuple,
     TypingNamedTuple,
@@ -38,12 +40,14 @@ pub enum SpecialExport {
     OsExit,
     Len,
     NoTypeCheck,
+    AbstractMethod,
 }
 
 impl SpecialExport {
     pub fn new(name: &Name) -> Option<Self> {
         match name.as_str() {
             "TypeAlias" => Some(Self::TypeAlias),
+            "ClassVar" => Some(Self::ClassVar),
             "TypeVar" => Some(Self::TypeVar),
             "ParamSpec" => Some(Self::ParamSpec),
             "TypeVarTuple" => Some(Self::TypeVarTuple),
@@ -52,6 +56,7 @@ impl SpecialExport {
             "Enum" => Some(Self::Enum),
             "StrEnum" => Some(Self::StrEnum),
             "IntEnum" => Some(Self::IntEnum),
+            "Flag" => Some(Self::Flag),
             "TypedDict" => Some(Self::TypedDict),
             "namedtuple" => Some(Self::CollectionsNamedTuple),
             "NamedTuple" => Some(Self::TypingNamedTuple),
@@ -66,6 +71,7 @@ impl SpecialExport {
             "_exit" => Some(Self::OsExit),
             "len" => Some(Self::Len),
             "no_type_check" => Some(Self::NoTypeCheck),
+            "abstractmethod" => Some(Self::AbstractMethod),
             _ => None,
         }
     }
@@ -73,6 +79,7 @@ impl SpecialExport {
     pub fn defined_in(self, m: ModuleName) -> bool {
         match self {
             Self::TypeAlias
+            | Self::ClassVar
             | Self::TypeVar
             | Self::ParamSpec
             | Self::TypeVarTuple
@@ -89,11 +96,14 @@ impl SpecialExport {
                 matches!(m.as_str(), "typing" | "typing_extensions")
             }
             Self::CollectionsNamedTuple => matches!(m.as_str(), "collections"),
-            Self::Enum | Self::StrEnum | Self::IntEnum => matches!(m.as_str(), "enum"),
+            Self::Enum | Self::StrEnum | Self::IntEnum | Self::Flag => {
+                matches!(m.as_str(), "enum")
+            }
             Self::Super | Self::Len => matches!(m.as_str(), "builtins"),
             Self::Exit => matches!(m.as_str(), "sys" | "builtins"),
             Self::Quit => matches!(m.as_str(), "builtins"),
             Self::OsExit => matches!(m.as_str(), "os"),
+            Self::AbstractMethod => matches!(m.as_str(), "abc"),
         }
     }
 }
@@ -30,6 +30,7 @@ use crate::solver::type_order::TypeOrder;
 use crate::types::callable::Callable;
 use crate::types::callable::Function;
 use crate::types::callable::Params;
+use crate::types::class::ClassType;
 use crate::types::module::Module;
 use crate::types::quantified::QuantifiedInfo;
 use crate::types::simplify::simplify_tuples;
@@ -102,6 +103,10 @@ impl Variable {
 #[derive(Debug)]
 pub struct Solver {
     variables: RwLock<SmallMap<Var, Variable>>,
+    /// Results of structural checks of a class type against a protocol, so we don't repeat
+    /// them. A solver lives for a single solve of a module, and any edit to the module or its
+    /// dependencies gets a fresh solver, so the cache can't go stale.
+    protocol_cache: RwLock<SmallMap<(ClassType, ClassType), bool>>,
 }
 
 impl Display for Solver {
@@ -122,6 +127,7 @@ impl Solver {
     pub fn new() -> Self {
         Self {
             variables: Default::default(),
+            protocol_cache: Default::default(),
         }
     }
 
@@ -559,6 +565,21 @@ impl<'a, Ans: LookupAnswer> Subset<'a, Ans> {
         self.solver.force_var(v)
     }
 
+    pub fn get_cached_protocol_check(&self, got: &ClassType, protocol: &ClassType) -> Option<bool> {
+        self.solver
+            .protocol_cache
+            .read()
+            .get(&(got.clone(), protocol.clone()))
+            .copied()
+    }
+
+    pub fn cache_protocol_check(&self, got: ClassType, protocol: ClassType, result: bool) {
+        self.solver
+            .protocol_cache
+            .write()
+            .insert((got, protocol), result);
+    }
+
     pub fn is_equal(&mut self, got: &Type, want: &Type) -> bool {
         self.is_subset_eq(got, want) && self.is_subset_eq(want, got)
     }
@@ -379,6 +379,32 @@ impl<'a, Ans: LookupAnswer> Subset<'a, Ans> {
     }
 
     pub fn is_subset_protocol(&mut self, got: Type, protocol: ClassType) -> bool {
+        // We can only reuse a result that was computed without relying on assumptions from an
+        // enclosing protocol check, and that couldn't have solved any variables along the way.
+        let has_var = |ty: &Type| ty.any(|t| matches!(t, Type::Var(_)));
+        let cache_key = match &got {
+            Type::ClassType(got_cls)
+                if self.recursive_assumptions.is_empty()
+                    && !has_var(&got)
+                    && !protocol.targs().as_slice().iter().any(has_var) =>
+            {
+                Some(got_cls.clone())
+            }
+            _ => None,
+        };
+        if let Some(got_cls) = &cache_key
+            && let Some(result) = self.get_cached_protocol_check(got_cls, &protocol)
+        {
+            return result;
+        }
+        let result = self.is_subset_protocol_uncached(got, protocol.clone());
+        if let Some(got_cls) = cache_key {
+            self.cache_protocol_check(got_cls, protocol, result);
+        }
+        result
+    }
+
+    fn is_subset_protocol_uncached(&mut self, got: Type, protocol: ClassType) -> bool {
         let recursive_check = (got.clone(), Type::ClassType(protocol.clone()));
         if !self.recursive_assumptions.insert(recursive_check) {
             // Assume recursive checks are true
@@ -386,11 +412,20 @@ impl<'a, Ans: LookupAnswer> Subset<'a, Ans> {
         }
         let to = self.type_order;
         let protocol_members = to.get_protocol_member_names(protocol.class_object());
+        let protocol_class_vars = to.get_protocol_class_var_names(protocol.class_object());
         for name in protocol_members {
             if name == dunder::INIT || name == dunder::NEW {
                 // Protocols can't be instantiated
                 continue;
             }
+            // A `ClassVar` protocol member must be provided by the class itself, so an instance
+            // variable doesn't satisfy it.
+            if let Type::ClassType(got_cls) = &got
+                && protocol_class_vars.contains(&name)
+                && !to.is_class_level_member(got_cls.class_object(), &name)
+            {
+                return false;
+            }
             if matches!(
                 got,
                 Type::Callable(_) | Type::Function(_) | Type::BoundMethod(_)
@@ -763,6 +798,9 @@ impl<'a, Ans: LookupAnswer> Subset<'a, Ans> {
                     want,
                 )
             }
+            // Fast path: a class type is always a subtype of itself, so skip the MRO lookup and
+            // the variance-aware comparison of type arguments.
+            (Type::ClassType(got), Type::ClassType(want)) if got == want => true,
             (Type::ClassType(ty), _) | (_, Type::ClassType(ty))
                 if self.type_order.extends_any(ty.class_object()) =>
             {
@@ -924,10 +962,9 @@ impl<'a, Ans: LookupAnswer> Subset<'a, Ans> {
                 if class == type_type {
                     // Unparameterized `type` is equivalent to `type[Any]`
                     true
-                } else if let Some(got_as_type) = self
-                    .type_order
-                    .as_superclass(class, type_type.class_object())
-                    && got_as_type.targs().is_empty()
+                } else if class
+                    .ancestor_args_for(type_type.class_object(), self.type_order)
+                    .is_some_and(|targs| targs.is_empty())
                 {
                     // The class extends unparameterized `type`
                     true
@@ -59,9 +59,7 @@ impl<'a, Ans: LookupAnswer> TypeOrder<'a, Ans> {
     pub fn has_metaclass(self, cls: &Class, metaclass: &ClassType) -> bool {
         let metadata = self.0.get_metadata_for_class(cls);
         match metadata.metaclass() {
-            Some(m) => {
-                self.0.as_superclass(m, metaclass.class_object()).as_ref() == Some(metaclass)
-            }
+            Some(m) => m.is_subclass_of(metaclass, self),
             None => metaclass == self.stdlib().builtins_type(),
         }
     }
@@ -83,6 +81,19 @@ impl<'a, Ans: LookupAnswer> TypeOrder<'a, Ans> {
         }
     }
 
+    pub fn get_protocol_class_var_names(self, cls: &Class) -> SmallSet<Name> {
+        let meta = self.0.get_metadata_for_class(cls);
+        if let Some(proto) = meta.protocol_metadata() {
+            proto.class_vars.clone()
+        } else {
+            SmallSet::new()
+        }
+    }
+
+    pub fn is_class_level_member(self, cls: &Class, name: &Name) -> bool {
+        self.0.is_class_level_member(cls, name)
+    }
+
     pub fn try_lookup_attr_from_class_type(
         self,
         cls: ClassType,
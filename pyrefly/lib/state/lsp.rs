@@ -666,6 +666,19 @@ impl<'a> Transaction<'a> {
         }
     }
 
+    /// If `ty` is a class object, render the class header along with its base classes, e.g.
+    /// `class C[T](A[int], B[T]): ...`.
+    pub fn display_class_with_bases(&self, handle: &Handle, ty: &Type) -> Option<String> {
+        let Type::ClassDef(cls) = ty else {
+            return None;
+        };
+        self.ad_hoc_solve(handle, |solver| {
+            solver
+                .get_metadata_for_class(cls)
+                .display_class_with_bases(cls)
+        })
+    }
+
     pub fn get_signature_help_at(
         &self,
         handle: &Handle,
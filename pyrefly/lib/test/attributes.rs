@@ -693,6 +693,30 @@ class Test(B):
     "#,
 );
 
+testcase!(
+    test_unknown_base_attribute_fallthrough,
+    r#"
+from typing import Any, NewType, assert_type
+from missing_module import Unknown  # E: Could not find import of `missing_module`
+
+class A(Unknown):
+    pass
+class B(A):
+    pass
+class C(B):
+    pass
+N = NewType("N", C)
+class Plain:
+    pass
+
+def test(a: A, c: C, n: N, p: Plain) -> None:
+    assert_type(a.z, Any)
+    assert_type(c.z, Any)
+    assert_type(n.z, Any)
+    p.z  # E: Object of class `Plain` has no attribute `z`
+    "#,
+);
+
 testcase!(
     test_field_using_method_scope_type_variable,
     r#"
@@ -971,3 +995,43 @@ A().y = [42]
 assert_type(A().y, list[Any])
     "#,
 );
+
+testcase!(
+    test_slots,
+    r#"
+class A:
+    __slots__ = ("x", "y")
+    def __init__(self) -> None:
+        self.x = 0
+        self.y = ""
+        self.z = 0.0  # E: Attribute `z` is not declared in `__slots__` of class `A`
+class B(A):
+    __slots__ = "w"
+    def __init__(self) -> None:
+        self.x = 1
+        self.w = 0
+        self.v = 0  # E: Attribute `v` is not declared in `__slots__` of class `B`
+class C:
+    __slots__ = ["x", "__dict__"]
+    def __init__(self) -> None:
+        self.anything = 0
+    "#,
+);
+
+testcase!(
+    test_slots_unrestricted,
+    r#"
+class NoSlots:
+    pass
+class A(NoSlots):
+    __slots__ = ("x",)
+    def __init__(self) -> None:
+        self.x = 0
+        self.y = 0
+def names() -> tuple[str, ...]: ...
+class B:
+    __slots__ = names()
+    def __init__(self) -> None:
+        self.y = 0
+    "#,
+);
@@ -80,7 +80,7 @@ testcase!(
     r#"
 def f(x: bool) -> bool: ...
 
-class A(foo=f(15)):  # E: Argument `Literal[15]` is not assignable to parameter `x` with type `bool`
+class A(foo=f(15)):  # E: Argument `Literal[15]` is not assignable to parameter `x` with type `bool`  # E: Class keyword argument `foo` is not accepted by `object.__init_subclass__`
     pass
 "#,
 );
@@ -120,7 +120,7 @@ class A(B0, B1):  # E:  Class `A` has metaclass `M0` which is not a subclass of
 testcase!(
     test_duplicate_class_keyword,
     r#"
-class A(foo="x" + 5, foo=True):  # E: Parse error: Duplicate keyword argument "foo"  # E: `+` is not supported between `Literal['x']` and `Literal[5]`
+class A(foo="x" + 5, foo=True):  # E: Parse error: Duplicate keyword argument "foo"  # E: `+` is not supported between `Literal['x']` and `Literal[5]`  # E: `foo` is not accepted  # E: `foo` is not accepted
     pass
 "#,
 );
@@ -140,3 +140,75 @@ f(C1)
 f(C2[int])
     "#,
 );
+
+testcase!(
+    test_init_subclass_not_classmethod,
+    r#"
+class A:
+    def __init_subclass__(cls) -> None: ...
+class B:
+    @classmethod
+    def __init_subclass__(cls) -> None: ...
+class C:
+    @staticmethod
+    def __init_subclass__() -> None: ...  # E: `__init_subclass__` is implicitly a classmethod and cannot be decorated with `@staticmethod`
+    "#,
+);
+
+testcase!(
+    test_init_subclass_keywords,
+    r#"
+class Base:
+    def __init_subclass__(cls, *, flag: bool = False) -> None: ...
+class A(Base, flag=True): ...
+class B(Base, flagg=True): ...  # E: Class keyword argument `flagg` is not accepted by `Base.__init_subclass__`
+class C(Base, flag="yes"): ...  # E: Class keyword argument `flag` has type `Literal['yes']`, which is not assignable to parameter type `bool` of `Base.__init_subclass__`
+class D(A, flag=False): ...
+class E(config=1): ...  # E: Class keyword argument `config` is not accepted by `object.__init_subclass__`
+"#,
+);
+
+testcase!(
+    test_init_subclass_kwargs,
+    r#"
+class Base:
+    def __init_subclass__(cls, **kwargs: int) -> None: ...
+class A(Base, x=1, y=2): ...
+class B(Base, x="no"): ...  # E: Class keyword argument `x` has type `Literal['no']`, which is not assignable to parameter type `int` of `Base.__init_subclass__`
+# A custom metaclass may accept keywords itself.
+class Meta(type): ...
+class C(metaclass=Meta, anything=1): ...
+"#,
+);
+
+testcase!(
+    test_init_subclass_keywords_later_base,
+    r#"
+class A: ...
+class B:
+    def __init_subclass__(cls, *, flag: bool = False) -> None: ...
+class C(A, B, flag=True): ...
+class D(A, B, flag=1): ...  # E: Class keyword argument `flag` has type `Literal[1]`, which is not assignable to parameter type `bool` of `B.__init_subclass__`
+"#,
+);
+
+testcase!(
+    test_init_subclass_keywords_any_base,
+    r#"
+from typing import Any
+class Base:
+    def __init_subclass__(cls) -> None: ...
+class A(Any, Base, opt=1): ...
+class B(A, opt=1): ...
+class C(Unknown, Base, opt=1): ...  # E: Could not find name `Unknown`
+"#,
+);
+
+testcase!(
+    test_object_init_subclass_keywords,
+    r#"
+class A(foo=1): ...  # E: Class keyword argument `foo` is not accepted by `object.__init_subclass__`
+class B(A): ...
+class C(B, bar="x"): ...  # E: Class keyword argument `bar` is not accepted by `object.__init_subclass__`
+"#,
+);
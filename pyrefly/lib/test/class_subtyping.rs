@@ -191,3 +191,38 @@ def f(b: B):
     assert_type(b.x3, int)
     "#,
 );
+
+testcase!(
+    test_generic_ancestor_args,
+    r#"
+from typing import Generic, TypeVar
+T = TypeVar("T")
+class A(Generic[T]): pass
+class B(A[list[T]]): pass
+class C(B[int]): pass
+class D: pass
+a1: A[list[int]] = C()
+a2: A[int] = C()  # E: `C` is not assignable to `A[int]`
+b1: B[int] = C()
+b2: B[str] = C()  # E: `C` is not assignable to `B[str]`
+a3: A[list[int]] = D()  # E: `D` is not assignable to `A[list[int]]`
+"#,
+);
+
+testcase!(
+    test_class_object_metaclass_subtyping,
+    r#"
+from typing import Any
+class Meta(type): pass
+class SubMeta(Meta): pass
+class A(metaclass=Meta): pass
+class B(metaclass=SubMeta): pass
+class C: pass
+m1: Meta = A
+m2: Meta = B
+m3: SubMeta = A  # E: `type[A]` is not assignable to `SubMeta`
+m4: Meta = C  # E: `type[C]` is not assignable to `Meta`
+def f(m: Meta) -> type[Any]:
+    return m
+"#,
+);
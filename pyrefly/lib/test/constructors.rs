@@ -280,6 +280,47 @@ assert_type(C(), C)
     "#,
 );
 
+testcase!(
+    test_metaclass_call_factory,
+    r#"
+from typing import assert_type
+class Product:
+    pass
+class Factory(type):
+    def __call__(cls, name: str) -> Product:
+        return Product()
+class SubFactory(Factory):
+    pass
+class A(metaclass=Factory):
+    pass
+class B(metaclass=SubFactory):
+    pass
+class C(A):
+    pass
+assert_type(A("a"), Product)
+assert_type(B("b"), Product)
+assert_type(C("c"), Product)
+A()  # E: Missing argument `name`
+    "#,
+);
+
+testcase!(
+    test_metaclass_call_returns_instance,
+    r#"
+from typing import assert_type
+class Singleton(type):
+    def __call__[T](cls: type[T], *args, **kwargs) -> T: ...
+class Config(metaclass=Singleton):
+    def __init__(self, path: str) -> None:
+        self.path = path
+class SubConfig(Config):
+    pass
+assert_type(Config("a"), Config)
+assert_type(SubConfig("b"), SubConfig)
+Config()  # E: Missing argument `path`
+    "#,
+);
+
 testcase!(
     test_metaclass_call_noreturn,
     r#"
@@ -395,3 +436,46 @@ class C[T]:
 assert_type(C(0), C[int])
     "#,
 );
+
+testcase!(
+    test_instantiate_abstract_class,
+    r#"
+import abc
+from abc import ABC, abstractmethod
+class Base(ABC):
+    @abstractmethod
+    def f(self) -> int: ...
+    @abc.abstractmethod
+    def g(self) -> int: ...
+class Partial(Base):
+    def f(self) -> int:
+        return 0
+class Concrete(Partial):
+    def g(self) -> int:
+        return 0
+class Impl:
+    def f(self) -> int:
+        return 0
+    def g(self) -> int:
+        return 0
+class ImplFirst(Impl, Base): pass
+class AbstractFirst(Base, Impl): pass
+class Redefined(ABC):
+    @abstractmethod
+    def f(self) -> int: ...
+    def f(self) -> int:
+        return 0
+class Plain: pass
+
+Base()  # E: Cannot instantiate `Base`
+Partial()  # E: Cannot instantiate `Partial` because the following members are abstract: `g`
+AbstractFirst()  # E: Cannot instantiate `AbstractFirst`
+Concrete()
+ImplFirst()
+Redefined()
+Plain()
+
+def f(cls: type[Base]) -> Base:
+    return cls()
+    "#,
+);
@@ -5,6 +5,8 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use crate::test::util::get_class;
+use crate::test::util::mk_state;
 use crate::testcase;
 
 testcase!(
@@ -583,6 +585,22 @@ InitVarTest("text", 3)   # OK
     "#,
 );
 
+testcase!(
+    test_initvar_default_and_kw_only,
+    r#"
+from dataclasses import dataclass, InitVar, KW_ONLY
+@dataclass
+class C:
+    x: int
+    y: InitVar[int] = 0
+    _: KW_ONLY
+    z: InitVar[str]
+C(1, z="")  # OK
+C(1, 2, z="")  # OK
+C(1, 2, "")  # E: Missing argument `z`  # E: Expected 2 positional arguments
+    "#,
+);
+
 testcase!(
     test_initvar_multiple_type_arguments,
     r#"
@@ -680,3 +698,67 @@ instance.count  # E: Object of class `InitVarTest` has no attribute `count`
 instance.value  # OK
     "#,
 );
+
+testcase!(
+    test_field_shadowed_by_method,
+    r#"
+from dataclasses import dataclass
+from typing import Callable
+def default() -> int: ...
+@dataclass
+class C:
+    x: int  # E: Dataclass field `x` has the same name as a method of `C`
+    f: Callable[[], int] = default
+    def x(self) -> int: ...
+    def g(self) -> int: ...
+    "#,
+);
+
+#[test]
+fn test_dataclass_field_types() {
+    let (handle, state) = mk_state(
+        r#"
+from dataclasses import KW_ONLY, InitVar, dataclass, field
+@dataclass
+class Data:
+    x: int
+    y: str = ""
+    z: list[int] = field(default_factory=list)
+    w: bool = field(default=False, init=False)
+    v: InitVar[int] = 0
+    _: KW_ONLY
+    k: bytes
+class NotADataclass:
+    x: int
+"#,
+    );
+    let data = get_class("Data", &handle, &state);
+    let not_a_dataclass = get_class("NotADataclass", &handle, &state);
+    state
+        .transaction()
+        .ad_hoc_solve(&handle, |solver| {
+            let fields = solver
+                .dataclass_field_types(&data)
+                .unwrap()
+                .into_iter()
+                .map(|field| {
+                    format!(
+                        "{}: {} default={} kw_only={}",
+                        field.name, field.ty, field.has_default, field.kw_only
+                    )
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(
+                fields,
+                vec![
+                    "x: int default=false kw_only=false",
+                    "y: str default=true kw_only=false",
+                    "z: list[int] default=true kw_only=false",
+                    "v: int default=true kw_only=false",
+                    "k: bytes default=false kw_only=true",
+                ]
+            );
+            assert!(solver.dataclass_field_types(&not_a_dataclass).is_none());
+        })
+        .unwrap();
+}
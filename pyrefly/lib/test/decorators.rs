@@ -175,6 +175,18 @@ reveal_type(f)  # E: revealed type: (x: int) -> int
     "#,
 );
 
+testcase!(
+    test_redundant_final_class_decorator,
+    r#"
+from typing import final
+@final
+class A: ...
+@final
+@final  # E: Redundant `@final` decorator
+class B: ...
+    "#,
+);
+
 testcase!(
     test_callable_class_as_decorator,
     r#"
@@ -323,3 +335,65 @@ def f0(arg: Callable[..., int]) -> Callable[..., int]: ...
 def f0(arg: Callable[..., int]) -> Callable[..., int]: ...
     "#,
 );
+
+testcase!(
+    test_total_ordering,
+    r#"
+from functools import total_ordering
+from typing import assert_type
+@total_ordering
+class A:
+    def __init__(self, x: int) -> None:
+        self.x = x
+    def __eq__(self, other: object) -> bool:
+        return isinstance(other, A) and self.x == other.x
+    def __lt__(self, other: "A") -> bool:
+        return self.x < other.x
+a = A(0)
+b = A(1)
+assert_type(a < b, bool)
+assert_type(a <= b, bool)
+assert_type(a > b, bool)
+assert_type(a >= b, bool)
+a.__le__(1)  # E: Argument `Literal[1]` is not assignable to parameter `other` with type `A`
+    "#,
+);
+
+testcase!(
+    test_total_ordering_inherited_root,
+    r#"
+from functools import total_ordering
+from typing import assert_type
+class Base:
+    def __ge__(self, other: "Base") -> bool: ...
+@total_ordering
+class A(Base):
+    pass
+assert_type(A() < A(), bool)
+assert_type(A() >= A(), bool)
+    "#,
+);
+
+testcase!(
+    test_total_ordering_no_ordering_method,
+    r#"
+from functools import total_ordering
+@total_ordering
+class A:  # E: Class `A` must define at least one ordering method
+    def __eq__(self, other: object) -> bool:
+        return True
+    "#,
+);
+
+testcase!(
+    test_singledispatchmethod,
+    r#"
+from functools import singledispatchmethod
+from typing import assert_type
+class A:
+    @singledispatchmethod
+    def f(self, x: object) -> str:
+        return ""
+assert_type(A().f(1), str)
+    "#,
+);
@@ -105,6 +105,40 @@ Color = Enum("C", 'RED', 'GREEN', 'BLUE')  # E: Expected string literal "Color"
 "#,
 );
 
+testcase!(
+    test_enum_functional_members,
+    r#"
+from typing import assert_type, Literal
+from enum import Enum, Flag
+
+Color = Enum('Color', 'RED GREEN')
+assert_type(Color.RED, Literal[Color.RED])
+assert_type(Color.GREEN.name, Literal["GREEN"])
+Color.BLUE  # E: Class `Color` has no class attribute `BLUE`
+
+Shape = Enum('Shape', [('CIRCLE', 1), ('SQUARE', 2)])
+assert_type(Shape.SQUARE, Literal[Shape.SQUARE])
+
+Perm = Flag('Perm', 'READ WRITE')
+assert_type(Perm.READ, Literal[Perm.READ])
+assert_type(Perm.READ | Perm.WRITE, Perm)
+"#,
+);
+
+testcase!(
+    test_enum_functional_non_literal_members,
+    r#"
+from typing import Any, assert_type
+from enum import Enum
+
+def get_names() -> list[str]: ...
+Dynamic = Enum('Dynamic', get_names())
+assert_type(Dynamic.ANYTHING, Any)
+def f(x: Dynamic) -> None:
+    assert_type(x, Any)
+"#,
+);
+
 testcase!(
     bug = "Matching EnumMeta against Iterable is failing because Type::to_unbound_callable() doesn't support generic methods",
     test_iterate,
@@ -168,7 +202,7 @@ class MyEnum(Enum):
     def D(self) -> None: pass
 
 reveal_type(MyEnum.A)  # E: revealed type: Literal[MyEnum.A]
-reveal_type(MyEnum.B)  # E: revealed type: nonmember[int]
+reveal_type(MyEnum.B)  # E: revealed type: int
 reveal_type(MyEnum.C)  # E: revealed type: Literal[MyEnum.C]
 reveal_type(MyEnum.D)  # E: revealed type: (self: Self@MyEnum) -> None
 "#,
@@ -219,6 +253,55 @@ def foo(f: MyFlag) -> None:
 "#,
 );
 
+testcase!(
+    test_int_enum_value,
+    r#"
+from enum import IntEnum, auto
+from typing import assert_type
+
+class Num(IntEnum):
+    ONE = 1
+    TWO = auto()
+
+assert_type(Num.ONE.value, int)
+assert_type(Num.TWO.value, int)
+def f(n: Num) -> None:
+    assert_type(n.value, int)
+    x: int = n
+"#,
+);
+
+testcase!(
+    test_enum_custom_mixin_value,
+    r#"
+from enum import Enum
+from typing import assert_type
+
+class Raw(bytes, Enum):
+    A = b"a"
+    B = b"b"
+
+assert_type(Raw.A.value, bytes)
+assert_type(Raw.B._value_, bytes)
+def f(r: Raw) -> None:
+    assert_type(r.value, bytes)
+    x: bytes = r
+
+class Plain(Enum):
+    A = 1
+assert_type(Plain.A.value, int)
+
+class Mixin:
+    def describe(self) -> str: ...
+class NotADataType(Mixin, Enum):
+    A = "a"
+assert_type(NotADataType.A.value, str)
+
+class TooMany(int, bytes, Enum):  # E: Enum `TooMany` has multiple data type mix-ins: `int`, `bytes`
+    X = 1
+"#,
+);
+
 testcase!(
     test_enum_instance_only_attr,
     r#"
@@ -347,3 +430,108 @@ class A(enum.IntEnum):
 assert_type(A.B, Literal[A.B])
     "#,
 );
+
+testcase!(
+    test_enum_verify_unique,
+    r#"
+from enum import Enum, EnumCheck, UNIQUE, verify
+@verify(UNIQUE)
+class Color(Enum):
+    RED = 1
+    GREEN = 2
+    CRIMSON = 1  # E: Enum member `CRIMSON` is an alias of `RED`, but `Color` is verified as `UNIQUE`
+@verify(EnumCheck.UNIQUE)
+class Shape(Enum):
+    SQUARE = 1
+    CIRCLE = 2
+    "#,
+);
+
+testcase!(
+    test_enum_verify_continuous,
+    r#"
+from enum import CONTINUOUS, Enum, Flag, verify
+@verify(CONTINUOUS)
+class Bad(Enum):  # E: Enum `Bad` is verified as `CONTINUOUS`, but is missing values: 3, 4
+    A = 1
+    B = 2
+    C = 5
+@verify(CONTINUOUS)
+class Good(Enum):
+    A = 1
+    B = 2
+    C = 3
+@verify(CONTINUOUS)
+class BadFlag(Flag):  # E: Enum `BadFlag` is verified as `CONTINUOUS`, but is missing values: 2
+    A = 1
+    C = 4
+    "#,
+);
+
+testcase!(
+    test_enum_verify_named_flags,
+    r#"
+from enum import Flag, NAMED_FLAGS, verify
+@verify(NAMED_FLAGS)
+class Color(Flag):
+    RED = 1
+    GREEN = 2
+    BLUE = 4
+    WHITE = 15  # E: Flag alias `WHITE` uses unnamed bits 0x8, but `Color` is verified as `NAMED_FLAGS`
+    YELLOW = 3
+    "#,
+);
+
+testcase!(
+    test_enum_member_wrapping_callable,
+    r#"
+from enum import Enum, member
+from typing import reveal_type
+class E(Enum):
+    A = member(lambda: 1)
+    B = lambda: 1
+reveal_type(E.A)  # E: revealed type: Literal[E.A]
+reveal_type(E.B)  # E: revealed type: () ->
+    "#,
+);
+
+testcase!(
+    test_enum_ignore,
+    r#"
+from enum import Enum
+from typing import reveal_type
+class ListIgnore(Enum):
+    _ignore_ = ["B", "C"]
+    A = 1
+    B = 2
+    C = 3
+class StrIgnore(Enum):
+    _ignore_ = "B C"
+    A = 1
+    B = 2
+    C = 3
+reveal_type(ListIgnore.A)  # E: revealed type: Literal[ListIgnore.A]
+ListIgnore.B  # E: Class `ListIgnore` has no class attribute `B`
+reveal_type(StrIgnore.A)  # E: revealed type: Literal[StrIgnore.A]
+StrIgnore.C  # E: Class `StrIgnore` has no class attribute `C`
+def f(x: ListIgnore) -> None:
+    x.C  # E: Object of class `ListIgnore` has no attribute `C`
+    "#,
+);
+
+testcase!(
+    test_enum_many_bases_metaclass,
+    r#"
+from enum import Flag
+class F0(Flag): pass
+class F1(Flag): pass
+class F2(Flag): pass
+class F3(Flag): pass
+class F4(Flag): pass
+class F5(Flag): pass
+class Wide(F0, F1, F2, F3, F4, F5): pass
+class M(type): pass
+class B(metaclass=M): pass
+class Bad(F0, F1, B): pass  # E: which is not a subclass of metaclass `M` from base class `B`
+"#,
+);
@@ -5,7 +5,10 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use crate::test::mro::get_class_metadata;
 use crate::test::util::TestEnv;
+use crate::test::util::get_class;
+use crate::test::util::mk_state;
 use crate::testcase;
 
 testcase!(
@@ -392,6 +395,36 @@ def f9(c1: C[int, str], c2: C[str]):
     "#,
 );
 
+testcase!(
+    test_default_only_tparam,
+    r#"
+from typing import Generic, TypeVar, assert_type
+T = TypeVar('T', default=int)
+class C(Generic[T]):
+    pass
+class D[T = str]:
+    pass
+def f(c1: C, c2: C[str], d1: D, d2: D[int]):
+    assert_type(c1, C[int])
+    assert_type(c2, C[str])
+    assert_type(d1, D[str])
+    assert_type(d2, D[int])
+    "#,
+);
+
+testcase!(
+    test_bare_generic_class_is_gradual,
+    r#"
+from typing import Any, assert_type
+class A[*Ts]:
+    def get(self) -> tuple[*Ts]: ...
+def f(x: list, y: dict, a: A):
+    assert_type(x, list[Any])
+    assert_type(y, dict[Any, Any])
+    assert_type(a.get(), tuple[Any, ...])
+    "#,
+);
+
 testcase!(
     test_bad_default_order,
     r#"
@@ -830,11 +863,11 @@ testcase!(
 from typing import Any, Generic
 
 # Explicit or implicit Any is not allowed.
-class C1(Generic[Any]):  # E: Expected a type variable, got `Any`
+class C1(Generic[Any]):  # E: Expected a type variable as an argument to `Generic`, got `Any`
     pass
 def f() -> Any: ...
 x = f()
-class C2(Generic[x]):  # E: Expected a type variable, got `Unknown`
+class C2(Generic[x]):  # E: Expected a type variable as an argument to `Generic`, got `Unknown`
     pass
 
 # But Any(Error) is.
@@ -842,7 +875,30 @@ T = oops()  # E:
 class C3(Generic[T]):
     pass
 
-class C4(Generic[int]):  # E: Expected a type variable, got `int`
+class C4(Generic[int]):  # E: Expected a type variable as an argument to `Generic`, got `int`
+    pass
+    "#,
+);
+
+testcase!(
+    test_generic_base_arguments,
+    r#"
+from typing import Generic, Protocol, TypeVar
+T = TypeVar("T")
+S = TypeVar("S")
+class A(Generic[int]):  # E: Expected a type variable as an argument to `Generic`, got `int`
+    pass
+class B(Generic[list[T]]):  # E: Expected a type variable as an argument to `Generic`, got `list[T]`
+    pass
+class C(Generic[T, T]):  # E: Duplicate type variable `T` in `Generic`
+    pass
+class D(Protocol[T, T]):  # E: Duplicate type variable `T` in `Protocol`
+    pass
+class E(Generic[T, S]):
+    pass
+class F(Generic[()]):  # E: Expected at least one type argument for `Generic`
+    pass
+class G(Protocol[()]):  # E: Expected at least one type argument for `Protocol`
     pass
     "#,
 );
@@ -869,3 +925,42 @@ def g[T2](x: type[T2]) -> T2:
     return x()
     "#,
 );
+
+testcase!(
+    test_generic_marker_before_generic_base,
+    r#"
+from typing import Generic, Protocol, TypeVar
+T = TypeVar("T")
+class A(Generic[T]): ...
+class B(A[int]): ...
+class P(Protocol): ...
+class C(Generic[T], A[T]): ...  # E: Base class `A` must be listed before `Generic`
+class D(Generic[T], B): ...  # E: Base class `B` must be listed before `Generic`
+class E(Protocol, P): ...  # E: Base class `P` must be listed before `Protocol`
+class F(A[T], Generic[T]): ...
+class G(Generic[T], list[T]): ...
+class H(Generic[T], int): ...
+    "#,
+);
+
+#[test]
+fn test_display_class_with_bases() {
+    let (handle, state) = mk_state(
+        r#"
+class A[T]: pass
+class B[K, V]: pass
+class C[T](A[int], B[str, T]): pass
+class D: pass
+class E(object): pass
+class F(D, object): pass
+"#,
+    );
+    let display = |name| {
+        get_class_metadata(name, &handle, &state)
+            .display_class_with_bases(&get_class(name, &handle, &state))
+    };
+    assert_eq!(display("C"), "class C[T](A[int], B[str, T]): ...");
+    assert_eq!(display("D"), "class D: ...");
+    assert_eq!(display("E"), "class E: ...");
+    assert_eq!(display("F"), "class F(D, object): ...");
+}
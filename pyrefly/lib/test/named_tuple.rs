@@ -5,7 +5,11 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use crate::test::util::get_class;
+use crate::test::util::mk_state;
 use crate::testcase;
+use crate::types::class::ClassType;
+use crate::types::class::TArgs;
 
 testcase!(
     test_named_tuple,
@@ -245,3 +249,65 @@ assert_type(Bar.z, int)
 assert_type(Bar(1, "y").w, int)
 "#,
 );
+
+testcase!(
+    test_named_tuple_generic,
+    r#"
+from typing import Generic, NamedTuple, TypeVar, assert_type
+T = TypeVar("T")
+class Pair(NamedTuple, Generic[T]):
+    x: T
+    y: list[T]
+def test(p: Pair[int]):
+    assert_type(p.x, int)
+    assert_type(p[0], int)
+    assert_type(p[1], list[int])
+    x, y = p
+    assert_type(x, int)
+    assert_type(y, list[int])
+    "#,
+);
+
+testcase!(
+    test_named_tuple_generic_pep695,
+    r#"
+from typing import NamedTuple, assert_type
+class Pair[T](NamedTuple):
+    x: T
+    y: str
+def test(p: Pair[int]):
+    match p:
+        case Pair(x, y):
+            assert_type(x, int)
+            assert_type(y, str)
+    "#,
+);
+
+#[test]
+fn test_named_tuple_field_types() {
+    let (handle, state) = mk_state(
+        r#"
+from typing import NamedTuple
+class Point(NamedTuple):
+    x: int
+    y: str = ""
+class NotATuple:
+    x: int
+"#,
+    );
+    let point = ClassType::new(get_class("Point", &handle, &state), TArgs::default());
+    let not_a_tuple = ClassType::new(get_class("NotATuple", &handle, &state), TArgs::default());
+    state
+        .transaction()
+        .ad_hoc_solve(&handle, |solver| {
+            let fields = solver
+                .named_tuple_field_types(&point)
+                .unwrap()
+                .into_iter()
+                .map(|(name, ty)| format!("{name}: {ty}"))
+                .collect::<Vec<_>>();
+            assert_eq!(fields, vec!["x: int", "y: str"]);
+            assert!(solver.named_tuple_field_types(&not_a_tuple).is_none());
+        })
+        .unwrap();
+}
@@ -76,12 +76,26 @@ from typing import NewType , TypedDict, Any
 class TD1(TypedDict):
     a: int
 
-BadNewType1 = NewType("BadNewType1", TD1)  # E: Second argument to NewType is invalid
+BadNewType1 = NewType("BadNewType1", TD1)  # E: Second argument to NewType cannot be a TypedDict
 
 BadNewType2 = NewType("BadNewType2", Any)  # E: Second argument to NewType is invalid
      "#,
 );
 
+testcase!(
+    test_new_type_of_new_type,
+    r#"
+from typing import NewType, assert_type
+UserId = NewType("UserId", int)
+AdminId = NewType("AdminId", UserId)
+def f(x: UserId) -> None: ...
+a = AdminId(UserId(1))
+assert_type(a, AdminId)
+f(a)
+AdminId(1)  # E: Argument `Literal[1]` is not assignable to parameter `_x` with type `UserId`
+     "#,
+);
+
 testcase!(
     test_new_type_as_alias,
     r#"
@@ -109,3 +123,17 @@ Foo((1, 2))  # OK
 Foo((1, 2, 3))  # this shouldn't be allowed
      "#,
 );
+
+testcase!(
+    test_new_type_chain,
+    r#"
+from typing import NewType
+class C: pass
+A = NewType("A", C)
+B = NewType("B", A)
+def f(a: A, c: C) -> None: ...
+b = B(A(C()))
+f(b, b)
+B(C())  # E: Argument `C` is not assignable to parameter `_x` with type `A`
+     "#,
+);
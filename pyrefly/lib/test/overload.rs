@@ -383,7 +383,7 @@ class C[T](Iterable[T]):
     def __new__(cls, x: T) -> Self: ...
 
 def g(x: int):
-    f(C(x))
+    f(C(x))  # E: Cannot instantiate `C`
     "#,
 );
 
@@ -7,6 +7,8 @@
 
 //! Many of these tests come from <https://typing.readthedocs.io/en/latest/spec/generics.html#paramspec>.
 
+use crate::test::util::get_class;
+use crate::test::util::mk_state;
 use crate::testcase;
 
 testcase!(
@@ -446,3 +448,35 @@ def test[**P](v: Callable[P, None]):
     b: Callable[P, None] = a
 "#,
 );
+
+testcase!(
+    test_generic_multiple_param_specs,
+    r#"
+from typing import Generic, ParamSpec
+P1 = ParamSpec("P1")
+P2 = ParamSpec("P2")
+class C(Generic[P1, P2]):  # E: There cannot be more than one ParamSpec type parameter
+    pass
+    "#,
+);
+
+#[test]
+fn test_generic_mixed_tparams_order() {
+    let (handle, state) = mk_state(
+        r#"
+from typing import Generic, ParamSpec, TypeVar, TypeVarTuple
+T = TypeVar("T")
+Ts = TypeVarTuple("Ts")
+P = ParamSpec("P")
+class C(Generic[T, *Ts, P]):
+    pass
+"#,
+    );
+    let cls = get_class("C", &handle, &state);
+    let names = cls
+        .tparams()
+        .iter()
+        .map(|p| p.name().as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["T", "Ts", "P"]);
+}
@@ -5,6 +5,10 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use std::sync::Arc;
+
+use crate::test::util::get_class;
+use crate::test::util::mk_state;
 use crate::testcase;
 
 // At some point in the past, this test took many minutes and consumed 50Gb of RAM.
@@ -44,3 +48,39 @@ class Configs:
         }
 "#,
 );
+
+// Class metadata is computed once per class and cached in the answers table, and a subclass keeps
+// the cached metadata of its bases rather than rebuilding it, so a deep hierarchy is linear rather
+// than quadratic in its depth.
+#[test]
+fn test_deep_class_hierarchy_metadata() {
+    let depth = 50;
+    let mut code = "from typing import Protocol\n".to_owned();
+    code.push_str("class C0(Protocol):\n    x0: int\n");
+    for i in 1..depth {
+        code.push_str(&format!(
+            "class C{i}(C{}, Protocol):\n    x{i}: int\n",
+            i - 1
+        ));
+    }
+    let (handle, state) = mk_state(&code);
+    let classes = (0..depth)
+        .map(|i| get_class(&format!("C{i}"), &handle, &state))
+        .collect::<Vec<_>>();
+    state
+        .transaction()
+        .ad_hoc_solve(&handle, |solver| {
+            for (i, pair) in classes.windows(2).enumerate() {
+                let [base_cls, cls] = pair else {
+                    unreachable!()
+                };
+                let metadata = solver.get_metadata_for_class(cls);
+                let base_metadata = solver.get_metadata_for_class(base_cls);
+                let (base, shared) = &metadata.bases_with_metadata()[0];
+                assert_eq!(base.class_object(), base_cls);
+                assert!(Arc::ptr_eq(shared, &base_metadata));
+                assert_eq!(metadata.protocol_metadata().unwrap().members.len(), i + 2);
+            }
+        })
+        .unwrap();
+}
@@ -43,7 +43,7 @@ from typing import Protocol
 class C1:
     x: int
     y: str
-class P1(Protocol, C1):  # E: If `Protocol` is included as a base class, all other bases must be protocols
+class P1(Protocol, C1):  # E: If `Protocol` is included as a base class, all other bases must be protocols, but `C1` is not a protocol
     x: int
 class P2(Protocol):
     x: int
@@ -52,6 +52,41 @@ class P3(Protocol, P2):
  "#,
 );
 
+testcase!(
+    test_protocol_base_object,
+    r#"
+from typing import Protocol
+class P1(Protocol):
+    x: int
+class P2(P1, Protocol, object):
+    y: str
+def f(p: P2) -> int:
+    return p.x
+ "#,
+);
+
+testcase!(
+    test_protocol_base_concrete,
+    r#"
+from typing import Protocol
+class P1(Protocol):
+    x: int
+class P2(P1, int, Protocol):  # E: all other bases must be protocols, but `int` is not a protocol
+    y: str
+ "#,
+);
+
+testcase!(
+    test_protocol_base_any,
+    r#"
+from typing import Any, Protocol
+class P1(Protocol):
+    x: int
+class P2(P1, Any, Protocol):
+    y: str
+ "#,
+);
+
 testcase!(
     test_callable_protocol,
     r#"
@@ -396,6 +431,23 @@ issubclass(ConcreteClass, RuntimeProtocol)
 "#,
 );
 
+testcase!(
+    test_redundant_runtime_checkable,
+    r#"
+from typing import Protocol, runtime_checkable
+@runtime_checkable
+class P1(Protocol):
+    def f(self) -> None: ...
+@runtime_checkable
+@runtime_checkable  # E: Redundant `@runtime_checkable` decorator
+class P2(Protocol):
+    def f(self) -> None: ...
+@runtime_checkable
+class P3(P1, Protocol):
+    def g(self) -> None: ...
+    "#,
+);
+
 testcase!(
     test_protocol_data_protocol_issubclass,
     r#"
@@ -431,6 +483,28 @@ issubclass(ConcreteClass, DataProtocol)  # E: Protocol `DataProtocol` has non-me
 "#,
 );
 
+testcase!(
+    test_protocol_callable_attribute_issubclass,
+    r#"
+from typing import Callable, Protocol, runtime_checkable
+
+@runtime_checkable
+class CallableAttribute(Protocol):
+    f: Callable[[], int]
+
+@runtime_checkable
+class InheritedCallableAttribute(CallableAttribute, Protocol):
+    def g(self) -> int: ...
+
+class C:
+    def f(self) -> int:
+        return 0
+
+issubclass(C, CallableAttribute)  # E: Protocol `CallableAttribute` has non-method members and cannot be used with issubclass()
+issubclass(C, InheritedCallableAttribute)  # E: Protocol `InheritedCallableAttribute` has non-method members and cannot be used with issubclass()
+"#,
+);
+
 testcase!(
     test_protocol_union_isinstance,
     r#"
@@ -562,3 +636,152 @@ class AnotherClass: # E: @runtime_checkable can only be applied to Protocol clas
     x: int = 5
 "#,
 );
+
+testcase!(
+    test_protocol_ignores_private_members,
+    r#"
+from typing import Protocol
+class P(Protocol):
+    x: int
+    __secret: str
+    def __private_helper(self) -> None: ...
+class C:
+    x: int
+def f(proto: P) -> None: ...
+def g(c: C) -> None:
+    f(c)
+"#,
+);
+
+testcase!(
+    test_protocol_final_method,
+    r#"
+from typing import Protocol, final
+class P(Protocol):
+    @final
+    def f(self) -> int: ...  # E: Protocol method `f` may not be marked `@final`
+    def g(self) -> int: ...
+class C(P):
+    @final
+    def f(self) -> int: ...
+"#,
+);
+
+testcase!(
+    test_protocol_data_members_issubclass,
+    r#"
+from typing import Protocol, runtime_checkable
+@runtime_checkable
+class MethodsOnly(Protocol):
+    def f(self) -> int: ...
+    __secret: int
+@runtime_checkable
+class Mixed(Protocol):
+    x: int
+    def f(self) -> int: ...
+@runtime_checkable
+class Inherited(Mixed, Protocol):
+    def g(self) -> int: ...
+class C:
+    x: int = 0
+    def f(self) -> int: ...
+    def g(self) -> int: ...
+issubclass(C, MethodsOnly)
+issubclass(C, Mixed)  # E: Protocol `Mixed` has non-method members and cannot be used with issubclass()
+issubclass(C, Inherited)  # E: Protocol `Inherited` has non-method members and cannot be used with issubclass()
+"#,
+);
+
+testcase!(
+    test_protocol_inherited_class_vars,
+    r#"
+from typing import ClassVar, Protocol
+class P(Protocol):
+    x: ClassVar[int]
+    y: int
+class Q(P, Protocol):
+    z: ClassVar[str]
+class Instance:
+    def __init__(self) -> None:
+        self.x: int = 0
+        self.y: int = 0
+        self.z: str = ""
+class Class:
+    x: ClassVar[int] = 0
+    y: int = 0
+    z: ClassVar[str] = ""
+q1: Q = Instance()  # E: `Instance` is not assignable to `Q`
+q2: Q = Class()
+"#,
+);
+
+testcase!(
+    test_protocol_class_var_member,
+    r#"
+from typing import ClassVar, Protocol, Sequence
+class P(Protocol):
+    x: ClassVar[Sequence[int]]
+class ClassVariable:
+    x: ClassVar[Sequence[int]] = [0]
+class InstanceVariableWithDefault:
+    x: Sequence[int] = [0]
+class InstanceVariable:
+    def __init__(self) -> None:
+        self.x: Sequence[int] = [0]
+p1: P = ClassVariable()
+p2: P = InstanceVariableWithDefault()  # E: `InstanceVariableWithDefault` is not assignable to `P`
+p3: P = InstanceVariable()  # E: `InstanceVariable` is not assignable to `P`
+"#,
+);
+
+testcase!(
+    test_protocol_class_var_detection,
+    r#"
+from typing import ClassVar, Protocol
+class P(Protocol):
+    x: ClassVar[int]
+class Q(Protocol):
+    x: int
+class C:
+    def __init__(self) -> None:
+        self.x: int = 0
+p: P = C()  # E: `C` is not assignable to `P`
+q: Q = C()
+"#,
+);
+
+testcase!(
+    test_protocol_metaclass,
+    r#"
+from abc import ABCMeta
+from typing import Protocol
+class Meta(type): ...
+class SubABCMeta(ABCMeta): ...
+class P1(Protocol, metaclass=ABCMeta): ...  # E: Protocol `P1` does not need `metaclass=ABCMeta`
+class P2(Protocol, metaclass=Meta): ...  # E: Metaclass of protocol `P2` has type `Meta` which is not a subclass of `ABCMeta`
+class P3(Protocol, metaclass=SubABCMeta): ...
+    "#,
+);
+
+testcase!(
+    test_numeric_protocols,
+    r#"
+from typing import SupportsIndex, SupportsInt
+class Index:
+    def __index__(self) -> int: ...
+class Int:
+    def __int__(self) -> int: ...
+class BadIndex:
+    def __index__(self) -> str: ...
+def f(x: SupportsIndex) -> None: ...
+def g(x: SupportsInt) -> None: ...
+f(Index())
+f(True)
+f(Int())  # E: Argument `Int` is not assignable to parameter `x` with type `SupportsIndex`
+f(BadIndex())  # E: Argument `BadIndex` is not assignable to parameter `x` with type `SupportsIndex`
+g(Int())
+g(Index())  # E: Argument `Index` is not assignable to parameter `x` with type `SupportsInt`
+[1, 2, 3][Index()]
+range(Index())
+    "#,
+);
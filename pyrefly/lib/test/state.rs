@@ -272,6 +272,28 @@ fn test_incremental_cyclic() {
     i.check(&["foo"], &["foo", "foo", "bar"]);
 }
 
+#[test]
+fn test_incremental_protocol_conformance() {
+    let mut i = Incremental::new();
+    i.set("foo", "class C:\n    def f(self) -> int: ...");
+    i.set(
+        "main",
+        "from typing import Protocol\nimport foo\nclass P(Protocol):\n    def f(self) -> int: ...\nx: P = foo.C()",
+    );
+    i.check(&["main"], &["main", "foo"]);
+    // `main` itself doesn't change, but we must not reuse the earlier result of checking
+    // `foo.C` against `P`.
+    i.set("foo", "class C:\n    def g(self) -> int: ...");
+    i.check_ignoring_loads_expectations(&["main"], &["main", "foo"]);
+    let errors = i
+        .state
+        .transaction()
+        .get_errors([&i.handle("main")])
+        .collect_errors()
+        .shown;
+    assert_eq!(errors.len(), 1);
+}
+
 /// Check that the interface is consistent as we change things.
 fn test_interface_consistent(code: &str) {
     let mut i = Incremental::new();
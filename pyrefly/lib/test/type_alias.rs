@@ -63,6 +63,22 @@ def f(x: X[int]):
     "#,
 );
 
+testcase!(
+    test_generic_alias_as_bare_base,
+    r#"
+from typing import TypeVar
+T = TypeVar("T")
+MyList = list[T]
+class C(MyList):  # E: Generic type alias `MyList` used as a base class without type arguments, leaving `T` unbound
+    pass
+class D(MyList[int]):
+    pass
+IntList = list[int]
+class E(IntList):
+    pass
+    "#,
+);
+
 testcase!(
     test_generic_alias_explicit,
     r#"
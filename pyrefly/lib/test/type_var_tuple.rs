@@ -83,6 +83,43 @@ class D(Generic[Ts]): ...  # E: `TypeVarTuple` must be unpacked
 "#,
 );
 
+testcase!(
+    test_type_var_tuple_specialization_arities,
+    r#"
+from typing import Generic, TypeVar, TypeVarTuple, Unpack, assert_type
+T = TypeVar("T")
+S = TypeVar("S")
+Ts = TypeVarTuple("Ts")
+class Array(Generic[Unpack[Ts]]):
+    def shape(self) -> tuple[Unpack[Ts]]: ...
+class Pair(Generic[T, Unpack[Ts], S]):
+    def first(self) -> T: ...
+    def middle(self) -> tuple[Unpack[Ts]]: ...
+    def last(self) -> S: ...
+def test(a1: Array[int], a3: Array[int, str, bytes], p2: Pair[int, str], p4: Pair[int, bytes, float, str]):
+    assert_type(a1.shape(), tuple[int])
+    assert_type(a3.shape(), tuple[int, str, bytes])
+    assert_type(p2.first(), int)
+    assert_type(p2.middle(), tuple[()])
+    assert_type(p2.last(), str)
+    assert_type(p4.first(), int)
+    assert_type(p4.middle(), tuple[bytes, float])
+    assert_type(p4.last(), str)
+"#,
+);
+
+testcase!(
+    test_generic_base_unpack_non_type_var_tuple,
+    r#"
+from typing import Generic, TypedDict, TypeVar, Unpack
+T = TypeVar("T")
+class TD(TypedDict):
+    x: int
+class A(Generic[T, Unpack[TD]]): ...  # E: `Unpack` in a generic base must be applied to a TypeVarTuple, got `TD`
+class B(Generic[Unpack[tuple[int, str]]]): ...  # E: `Unpack` in a generic base must be applied to a TypeVarTuple, got `tuple[int, str]`
+"#,
+);
+
 testcase!(
     test_type_var_tuple_instantiation,
     r#"
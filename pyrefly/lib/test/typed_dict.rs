@@ -42,7 +42,7 @@ testcase!(
     r#"
 from typing import TypedDict, Required, NotRequired, ReadOnly, ClassVar, Final
 class MyDict(TypedDict):
-    v: ClassVar[int]  # E: `ClassVar` may not be used for TypedDict or NamedTuple members
+    v: ClassVar[int]  # E: TypedDict key `v` may not be a `ClassVar`
     w: Final[int]  # E: `Final` may not be used for TypedDict or NamedTuple members
     x: NotRequired  # E: Expected a type argument for `NotRequired`
     y: Required  # E: Expected a type argument for `Required`
@@ -827,3 +827,51 @@ class TD3(TypedDict, bar="test", baz=False):  # E: TypedDict does not support ke
     x: int
 "#,
 );
+
+testcase!(
+    test_typed_dict_in_narrowing,
+    r#"
+from typing import TypedDict, NotRequired, assert_type, final
+class TD(TypedDict):
+    a: int
+    b: NotRequired[str]
+def f(td: TD) -> None:
+    if "b" in td:
+        assert_type(td["b"], str)
+
+@final
+class A(TypedDict):
+    tag: int
+@final
+class B(TypedDict):
+    other: str
+class C(TypedDict):
+    maybe: NotRequired[int]
+
+def g(x: A | B) -> None:
+    if "tag" in x:
+        assert_type(x, A)
+    else:
+        assert_type(x, B)
+
+def h(x: A | C) -> None:
+    if "maybe" not in x:
+        assert_type(x, A | C)
+    if "tag" not in x:
+        assert_type(x, C)
+    if "tag" in x:
+        # `C` isn't final, so it may still have a `tag` key.
+        assert_type(x, A | C)
+"#,
+);
+
+testcase!(
+    test_typed_dict_class_var_not_a_key,
+    r#"
+from typing import ClassVar, TypedDict
+class TD(TypedDict):
+    x: int
+    y: ClassVar[int]  # E: TypedDict key `y` may not be a `ClassVar`
+td: TD = {"x": 1}
+    "#,
+);
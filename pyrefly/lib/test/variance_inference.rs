@@ -275,3 +275,56 @@ y = b.f(3)
 z = b.f(3.0) # E:
 "#,
 );
+
+testcase!(
+    test_inferred_variance_by_position,
+    r#"
+class OnlyReturn[T]:
+    def get(self) -> T: ...
+
+class OnlyParam[T]:
+    def put(self, x: T) -> None: ...
+
+class Mutable[T]:
+    x: T
+
+def f(r: OnlyReturn[int], p: OnlyParam[float], m: Mutable[int]):
+    r1: OnlyReturn[float] = r
+    p1: OnlyParam[int] = p
+    m1: Mutable[float] = m  # E: `Mutable[int]` is not assignable to `Mutable[float]`
+"#,
+);
+
+testcase!(
+    test_declared_variance_matches_usage,
+    r#"
+from typing import Generic, TypeVar
+T_co = TypeVar("T_co", covariant=True)
+T_contra = TypeVar("T_contra", contravariant=True)
+
+class Producer(Generic[T_co]):
+    def __init__(self, x: T_co) -> None: ...
+    def get(self) -> T_co: ...
+
+class Consumer(Generic[T_contra]):
+    def put(self, x: T_contra) -> None: ...
+"#,
+);
+
+testcase!(
+    test_declared_variance_conflicts_with_usage,
+    r#"
+from typing import Generic, TypeVar
+T_co = TypeVar("T_co", covariant=True)
+T_contra = TypeVar("T_contra", contravariant=True)
+
+class BadProducer(Generic[T_co]):  # E: Type variable `T_co` is declared covariant but is used in contravariant position in class `BadProducer`
+    def put(self, x: T_co) -> None: ...
+
+class BadConsumer(Generic[T_contra]):  # E: Type variable `T_contra` is declared contravariant but is used in covariant position in class `BadConsumer`
+    def get(self) -> T_contra: ...
+
+class BadMutable(Generic[T_co]):  # E: Type variable `T_co` is declared covariant but is used in invariant position in class `BadMutable`
+    x: T_co
+"#,
+);
@@ -277,7 +277,7 @@ class Foo(Awaitable[int]):
 async def bar() -> str: ...
 
 async def test() -> None:
-    assert_type(await Foo(), int)
+    assert_type(await Foo(), int)  # E: Cannot instantiate `Foo`
     assert_type(await bar(), str)
 "#,
 );
@@ -289,7 +289,7 @@ from typing import Awaitable, Literal
 class Foo(Awaitable[Literal[42]]):
     pass
 async def test() -> Literal[42]:
-    return await Foo()
+    return await Foo()  # E: Cannot instantiate `Foo`
 "#,
 );
 
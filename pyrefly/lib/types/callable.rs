@@ -248,6 +248,7 @@ pub enum FunctionKind {
     RevealType,
     Final,
     RuntimeCheckable,
+    TotalOrdering,
     PropertySetter(Box<FuncId>),
     Def(Box<FuncId>),
     AbstractMethod,
@@ -530,6 +531,7 @@ impl FunctionKind {
             ("typing", None, "runtime_checkable") => Self::RuntimeCheckable,
             ("typing_extensions", None, "runtime_checkable") => Self::RuntimeCheckable,
             ("abc", None, "abstractmethod") => Self::AbstractMethod,
+            ("functools", None, "total_ordering") => Self::TotalOrdering,
             _ => Self::Def(Box::new(FuncId {
                 module,
                 cls: cls.cloned(),
@@ -600,6 +602,11 @@ impl FunctionKind {
                 cls: None,
                 func: Name::new_static("runtime_checkable"),
             },
+            Self::TotalOrdering => FuncId {
+                module: ModuleName::functools(),
+                cls: None,
+                func: Name::new_static("total_ordering"),
+            },
             Self::CallbackProtocol(cls) => FuncId {
                 module: cls.qname().module_name(),
                 cls: Some(cls.name().clone()),
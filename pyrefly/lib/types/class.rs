@@ -5,6 +5,7 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use std::borrow::Cow;
 use std::cmp::Ord;
 use std::cmp::Ordering;
 use std::fmt;
@@ -12,6 +13,7 @@ use std::fmt::Debug;
 use std::fmt::Display;
 use std::hash::Hash;
 use std::hash::Hasher;
+use std::mem;
 use std::sync::Arc;
 
 use dupe::Dupe;
@@ -27,13 +29,16 @@ use ruff_python_ast::name::Name;
 use ruff_text_size::TextRange;
 use starlark_map::small_map::SmallMap;
 
+use crate::alt::answers::LookupAnswer;
 use crate::module::module_info::ModuleInfo;
 use crate::module::module_name::ModuleName;
 use crate::module::module_path::ModulePath;
+use crate::solver::type_order::TypeOrder;
 use crate::types::equality::TypeEq;
 use crate::types::qname::QName;
 use crate::types::quantified::Quantified;
 use crate::types::quantified::QuantifiedKind;
+use crate::types::simplify::simplify_tuples;
 use crate::types::types::TParams;
 use crate::types::types::Type;
 
@@ -128,6 +133,22 @@ impl Class {
             .is_some_and(|prop| prop.is_annotated)
     }
 
+    /// Is this field annotated with `ClassVar` in the class body?
+    pub fn is_field_class_var(&self, name: &Name) -> bool {
+        self.0
+            .fields
+            .get(name)
+            .is_some_and(|prop| prop.is_class_var())
+    }
+
+    /// Is this field a method decorated with `@abstractmethod` in the class body?
+    pub fn is_field_abstract_method(&self, name: &Name) -> bool {
+        self.0
+            .fields
+            .get(name)
+            .is_some_and(|prop| prop.is_abstract_method)
+    }
+
     pub fn field_decl_range(&self, name: &Name) -> Option<TextRange> {
         Some(self.0.fields.get(name)?.range)
     }
@@ -197,12 +218,16 @@ impl Visit<Type> for Class {
 #[derive(Debug, Clone)]
 pub struct ClassFieldProperties {
     is_annotated: bool,
+    is_class_var: bool,
+    is_abstract_method: bool,
     range: TextRange,
 }
 
 impl PartialEq for ClassFieldProperties {
     fn eq(&self, other: &Self) -> bool {
         self.is_annotated == other.is_annotated
+            && self.is_class_var == other.is_class_var
+            && self.is_abstract_method == other.is_abstract_method
     }
 }
 
@@ -215,12 +240,24 @@ impl TypeEq for ClassFieldProperties {}
 pub struct ClassDefIndex(pub u32);
 
 impl ClassFieldProperties {
-    pub fn new(is_annotated: bool, range: TextRange) -> Self {
+    pub fn new(
+        is_annotated: bool,
+        is_class_var: bool,
+        is_abstract_method: bool,
+        range: TextRange,
+    ) -> Self {
         Self {
             is_annotated,
+            is_class_var,
+            is_abstract_method,
             range,
         }
     }
+
+    /// Is this field annotated with `ClassVar`?
+    pub fn is_class_var(&self) -> bool {
+        self.is_class_var
+    }
 }
 
 #[derive(TypeEq, Eq, PartialEq)]
@@ -286,6 +323,18 @@ impl TArgs {
         Self(targs.into_boxed_slice())
     }
 
+    /// Type arguments for a generic class that is referenced without any, e.g. `list` used as an
+    /// annotation means `list[Any]`. Each type parameter gets its default if it has one, and
+    /// otherwise an implicit `Any` (`tuple[Any, ...]` for a TypeVarTuple, `...` for a ParamSpec).
+    pub fn for_unparametrized(cls: &Class) -> Self {
+        Self::new(
+            cls.tparams()
+                .quantified()
+                .map(|q| q.as_gradual_type())
+                .collect(),
+        )
+    }
+
     pub fn as_slice(&self) -> &[Type] {
         &self.0
     }
@@ -316,19 +365,58 @@ impl TArgs {
     }
 }
 
-pub struct Substitution<'a>(SmallMap<&'a Quantified, &'a Type>);
+pub struct Substitution<'a> {
+    map: SmallMap<&'a Quantified, Cow<'a, Type>>,
+    /// Whether any of the type parameters is a TypeVarTuple, in which case substituted types may
+    /// contain unpacked tuples that need to be spliced into their surrounding tuple.
+    has_type_var_tuple: bool,
+}
 
 impl<'a> Substitution<'a> {
     pub fn substitute(&self, ty: Type) -> Type {
-        ty.subst(&self.0)
+        let mut ty = ty.subst(&self.map);
+        if self.has_type_var_tuple {
+            ty.transform_mut(&mut |x| {
+                if let Type::Tuple(tuple) = x {
+                    *x = simplify_tuples(mem::take(tuple));
+                }
+            });
+        }
+        ty
     }
 
     /// Creates a Substitution from a class specialized with type arguments.
-    /// Assumes that the number of args equals the number of type parameters on the class.
+    ///
+    /// Normally there is exactly one argument per type parameter. If there are more or fewer
+    /// arguments and the class has a TypeVarTuple parameter, the TypeVarTuple captures (as a
+    /// tuple) whatever arguments are not matched by the type parameters before and after it.
     pub fn new(cls: &'a Class, args: &'a TArgs) -> Self {
         let tparams = cls.tparams();
         let targs = args.as_slice();
-        Substitution(tparams.quantified().zip(targs.iter()).collect())
+        let type_var_tuple_idx = tparams.quantified().position(|q| q.is_type_var_tuple());
+        let map = match type_var_tuple_idx {
+            Some(idx) if targs.len() != tparams.len() && targs.len() + 1 >= tparams.len() => {
+                let middle_end = targs.len() - (tparams.len() - idx - 1);
+                let mut map = SmallMap::new();
+                for (i, q) in tparams.quantified().enumerate() {
+                    let arg = match i.cmp(&idx) {
+                        Ordering::Less => Cow::Borrowed(&targs[i]),
+                        Ordering::Equal => Cow::Owned(Type::tuple(targs[idx..middle_end].to_vec())),
+                        Ordering::Greater => Cow::Borrowed(&targs[i - idx - 1 + middle_end]),
+                    };
+                    map.insert(q, arg);
+                }
+                map
+            }
+            _ => tparams
+                .quantified()
+                .zip(targs.iter().map(Cow::Borrowed))
+                .collect(),
+        };
+        Substitution {
+            map,
+            has_type_var_tuple: type_var_tuple_idx.is_some(),
+        }
     }
 }
 
@@ -368,6 +456,30 @@ impl ClassType {
         &self.0
     }
 
+    /// Is this class type a subclass of `other`, including the type arguments? For example,
+    /// given `class B(A[int])`, `B` is a subclass of `A[int]` but not of `A[str]`.
+    pub fn is_subclass_of<Ans: LookupAnswer>(
+        &self,
+        other: &ClassType,
+        type_order: TypeOrder<Ans>,
+    ) -> bool {
+        self.ancestor_args_for(other.class_object(), type_order)
+            .is_some_and(|targs| &targs == other.targs())
+    }
+
+    /// The type arguments of `target` as seen through the MRO of this class type, or `None`
+    /// if `target` is not an ancestor. For example, given `class B(A[list[T]])`, the
+    /// arguments of `A` for `B[int]` are `[list[int]]`.
+    pub fn ancestor_args_for<Ans: LookupAnswer>(
+        &self,
+        target: &Class,
+        type_order: TypeOrder<Ans>,
+    ) -> Option<TArgs> {
+        type_order
+            .as_superclass(self, target)
+            .map(|ancestor| ancestor.1)
+    }
+
     pub fn tparams(&self) -> &TParams {
         self.0.tparams()
     }
@@ -411,4 +523,244 @@ impl ClassType {
     pub fn is_builtin(&self, name: &str) -> bool {
         self.0.is_builtin(name)
     }
+
+    /// Equality that treats `Any` type arguments as wildcards, so `list[Any]` is gradually equal to
+    /// `list[int]`, and so is `list[list[Any]]` to `list[list[int]]`.
+    pub fn gradually_equal(&self, other: &Self) -> bool {
+        fn targ_equal(x: &Type, y: &Type) -> bool {
+            match (x, y) {
+                (Type::Any(_), _) | (_, Type::Any(_)) => true,
+                (Type::ClassType(x), Type::ClassType(y)) => x.gradually_equal(y),
+                _ => x == y,
+            }
+        }
+        self.0 == other.0
+            && self.1.as_slice().len() == other.1.as_slice().len()
+            && self
+                .1
+                .as_slice()
+                .iter()
+                .zip(other.1.as_slice())
+                .all(|(x, y)| targ_equal(x, y))
+    }
+
+    /// Display the type arguments of this class type, e.g. `[int, str]`, or the empty string if
+    /// there are none. Useful for error messages that mention a class's specialization.
+    pub fn display_targs_or_empty(&self) -> String {
+        if self.1.is_empty() {
+            String::new()
+        } else {
+            format!("[{}]", commas_iter(|| self.1.as_slice()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use dupe::Dupe;
+    use pyrefly_util::uniques::UniqueFactory;
+    use ruff_text_size::TextRange;
+    use ruff_text_size::TextSize;
+
+    use crate::types::class::Class;
+    use crate::types::class::ClassFieldProperties;
+    use crate::types::class::ClassKind;
+    use crate::types::class::ClassType;
+    use crate::types::class::Substitution;
+    use crate::types::class::TArgs;
+    use crate::types::display::tests::fake_class;
+    use crate::types::display::tests::fake_tparam;
+    use crate::types::quantified::QuantifiedKind;
+    use crate::types::tuple::Tuple;
+    use crate::types::types::AnyStyle;
+    use crate::types::types::Type;
+
+    fn fake_instance(name: &str) -> Type {
+        fake_class(name, name, 5, Vec::new())
+            .as_class_type()
+            .to_type()
+    }
+
+    /// A generic class with one fresh type parameter per `(name, kind)` pair.
+    fn fake_generic_class(
+        uniques: &UniqueFactory,
+        name: &str,
+        module: &str,
+        range: u32,
+        tparams: &[(&str, QuantifiedKind)],
+    ) -> Class {
+        fake_class(
+            name,
+            module,
+            range,
+            tparams
+                .iter()
+                .map(|(tparam, kind)| fake_tparam(uniques, tparam, *kind))
+                .collect(),
+        )
+    }
+
+    fn tparam_types(cls: &Class) -> Vec<Type> {
+        cls.tparams()
+            .quantified()
+            .map(|q| q.clone().to_type())
+            .collect()
+    }
+
+    #[test]
+    fn test_substitution_type_var_tuple() {
+        let uniques = UniqueFactory::new();
+        let int = fake_instance("int");
+        let str = fake_instance("str");
+        let bytes = fake_instance("bytes");
+        let float = fake_instance("float");
+        let pair = fake_generic_class(
+            &uniques,
+            "Pair",
+            "mod",
+            10,
+            &[
+                ("T", QuantifiedKind::TypeVar),
+                ("Ts", QuantifiedKind::TypeVarTuple),
+                ("S", QuantifiedKind::TypeVar),
+            ],
+        );
+        let [t, ts, s] = tparam_types(&pair).try_into().unwrap();
+        let unpacked_ts = Type::Tuple(Tuple::unpacked(Vec::new(), ts, Vec::new()));
+
+        let targs = TArgs::new(vec![int.clone(), str.clone(), bytes.clone(), float.clone()]);
+        let substitution = Substitution::new(&pair, &targs);
+        assert_eq!(substitution.substitute(t.clone()), int);
+        assert_eq!(
+            substitution.substitute(unpacked_ts.clone()),
+            Type::tuple(vec![str.clone(), bytes.clone()])
+        );
+        assert_eq!(substitution.substitute(s.clone()), float);
+
+        // The TypeVarTuple captures no arguments.
+        let targs = TArgs::new(vec![int.clone(), float.clone()]);
+        let substitution = Substitution::new(&pair, &targs);
+        assert_eq!(substitution.substitute(t), int);
+        assert_eq!(
+            substitution.substitute(unpacked_ts),
+            Type::tuple(Vec::new())
+        );
+        assert_eq!(substitution.substitute(s), float);
+    }
+
+    #[test]
+    fn test_substitution_only_type_var_tuple() {
+        let uniques = UniqueFactory::new();
+        let int = fake_instance("int");
+        let str = fake_instance("str");
+        let array = fake_generic_class(
+            &uniques,
+            "Array",
+            "mod",
+            10,
+            &[("Ts", QuantifiedKind::TypeVarTuple)],
+        );
+        let [ts] = tparam_types(&array).try_into().unwrap();
+        let unpacked_ts = Type::Tuple(Tuple::unpacked(vec![int.clone()], ts, Vec::new()));
+
+        let targs = TArgs::new(vec![str.clone(), str.clone()]);
+        assert_eq!(
+            Substitution::new(&array, &targs).substitute(unpacked_ts.clone()),
+            Type::tuple(vec![int.clone(), str.clone(), str.clone()])
+        );
+        let targs = TArgs::default();
+        assert_eq!(
+            Substitution::new(&array, &targs).substitute(unpacked_ts),
+            Type::tuple(vec![int])
+        );
+    }
+
+    #[test]
+    fn test_display_targs_or_empty() {
+        let uniques = UniqueFactory::new();
+        let int = fake_class("int", "builtins", 5, Vec::new()).as_class_type();
+        let str = fake_instance("str");
+        let dict = fake_generic_class(
+            &uniques,
+            "dict",
+            "builtins",
+            10,
+            &[
+                ("K", QuantifiedKind::TypeVar),
+                ("V", QuantifiedKind::TypeVar),
+            ],
+        );
+        assert_eq!(int.display_targs_or_empty(), "");
+        let dict_str_int = ClassType::new(dict, TArgs::new(vec![str, int.to_type()]));
+        assert_eq!(dict_str_int.display_targs_or_empty(), "[str, int]");
+    }
+
+    #[test]
+    fn test_gradually_equal() {
+        let uniques = UniqueFactory::new();
+        let int = fake_instance("int");
+        let str = fake_instance("str");
+        let any = Type::Any(AnyStyle::Explicit);
+        let list = fake_generic_class(
+            &uniques,
+            "list",
+            "builtins",
+            10,
+            &[("T", QuantifiedKind::TypeVar)],
+        );
+        let list_of = |t: Type| ClassType::new(list.dupe(), TArgs::new(vec![t]));
+        assert!(list_of(any.clone()).gradually_equal(&list_of(int.clone())));
+        assert!(list_of(int.clone()).gradually_equal(&list_of(any.clone())));
+        assert!(list_of(int.clone()).gradually_equal(&list_of(int.clone())));
+        assert!(!list_of(int.clone()).gradually_equal(&list_of(str.clone())));
+        assert!(
+            list_of(list_of(any.clone()).to_type())
+                .gradually_equal(&list_of(list_of(int.clone()).to_type()))
+        );
+        assert!(!list_of(list_of(str).to_type()).gradually_equal(&list_of(list_of(int).to_type())));
+        assert!(
+            !list_of(any)
+                .gradually_equal(&fake_class("int", "builtins", 5, Vec::new()).as_class_type())
+        );
+    }
+
+    #[test]
+    fn test_class_kind_from_qname() {
+        let kind = |module, name| fake_class(name, module, 5, Vec::new()).kind();
+        assert!(matches!(
+            kind("builtins", "staticmethod"),
+            ClassKind::StaticMethod
+        ));
+        assert!(matches!(kind("functools", "wraps"), ClassKind::Class));
+        // These are functions or plain classes in typeshed, so they are not special-cased.
+        assert!(matches!(kind("abc", "abstractmethod"), ClassKind::Class));
+        assert!(matches!(
+            kind("functools", "singledispatch"),
+            ClassKind::Class
+        ));
+        assert!(matches!(
+            kind("functools", "singledispatchmethod"),
+            ClassKind::Class
+        ));
+    }
+
+    #[test]
+    fn test_class_field_properties_class_var() {
+        let range = TextRange::default();
+        let class_var = ClassFieldProperties::new(true, true, false, range);
+        let instance_var = ClassFieldProperties::new(true, false, false, range);
+        assert!(class_var.is_class_var());
+        assert!(!instance_var.is_class_var());
+        // The flag participates in equality, but the range does not.
+        assert_ne!(class_var, instance_var);
+        assert_eq!(
+            class_var,
+            ClassFieldProperties::new(
+                true,
+                true,
+                false,
+                TextRange::new(TextSize::new(1), TextSize::new(2))
+            )
+        );
+    }
 }
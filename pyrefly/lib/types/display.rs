@@ -420,7 +420,7 @@ pub mod tests {
         )
     }
 
-    fn fake_tparam(uniques: &UniqueFactory, name: &str, kind: QuantifiedKind) -> TParam {
+    pub fn fake_tparam(uniques: &UniqueFactory, name: &str, kind: QuantifiedKind) -> TParam {
         TParam {
             quantified: Quantified::new(
                 uniques.fresh(),
@@ -5,6 +5,7 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use std::borrow::Borrow;
 use std::fmt;
 use std::fmt::Display;
 use std::sync::Arc;
@@ -840,13 +841,13 @@ impl Type {
         }
     }
 
-    pub fn subst(mut self, mp: &SmallMap<&Quantified, &Type>) -> Self {
+    pub fn subst<T: Borrow<Type>>(mut self, mp: &SmallMap<&Quantified, T>) -> Self {
         // We are looking up Quantified in a map, and Quantified may contain a Quantified within it.
         // Therefore, to make sure we still get matches, work top-down (not using `transform`).
-        fn f(ty: &mut Type, mp: &SmallMap<&Quantified, &Type>) {
+        fn f<T: Borrow<Type>>(ty: &mut Type, mp: &SmallMap<&Quantified, T>) {
             if let Type::Quantified(x) = ty {
                 if let Some(w) = mp.get(x) {
-                    *ty = (*w).clone();
+                    *ty = w.borrow().clone();
                 }
             } else {
                 ty.recurse_mut(&mut |x| f(x, mp));